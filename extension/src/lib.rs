@@ -1,8 +1,17 @@
 use std::fs;
-use zed_extension_api::{self as zed, serde_json, LanguageServerId, Result};
+use zed_extension_api::{self as zed, serde_json, settings::LspSettings, LanguageServerId, Result};
+
+/// Extension-side options from `lsp.laravel-ls.settings`
+#[derive(Debug, Default, Clone)]
+struct LaravelSettings {
+    /// Require destructive slash commands to be run manually
+    confirm_destructive: bool,
+}
 
 struct LaravelExtension {
     server_script_path: Option<String>,
+    /// Last settings seen when the server started, for callbacks that don't get a worktree
+    settings: LaravelSettings,
 }
 
 impl LaravelExtension {
@@ -50,6 +59,9 @@ impl LaravelExtension {
         }
 
         // 3. Install from npm
+        //
+        // `npm_install_package` runs inside Zed and only hands back the installer's output
+        // when it fails, so progress stays at `Downloading` until the install finishes.
         zed::set_language_server_installation_status(
             language_server_id,
             &zed::LanguageServerInstallationStatus::Downloading,
@@ -66,10 +78,15 @@ impl LaravelExtension {
                 Ok(npm_entry.to_string())
             }
             Err(e) => {
+                // Prefer the installer's own error line over the full output
+                let reason = e
+                    .lines()
+                    .find_map(install_error_for_line)
+                    .unwrap_or_else(|| e.clone());
                 zed::set_language_server_installation_status(
                     language_server_id,
                     &zed::LanguageServerInstallationStatus::Failed(format!(
-                        "Failed to install Laravel Language Server: {e}"
+                        "Failed to install Laravel Language Server: {reason}"
                     )),
                 );
                 Err(e)
@@ -78,38 +95,859 @@ impl LaravelExtension {
     }
 }
 
+/// The error message from a line of npm or pnpm install output, if the line reports one
+fn install_error_for_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    let npm_error = line
+        .strip_prefix("npm ERR!")
+        .or_else(|| line.strip_prefix("npm error"));
+    if let Some(message) = npm_error {
+        let message = message.trim();
+        return (!message.is_empty()).then(|| message.to_string());
+    }
+    line.starts_with("ERR_PNPM_").then(|| line.to_string())
+}
+
+/// Read the `lsp.<server id>` settings for a worktree, treating missing settings as empty
+fn lsp_settings(language_server_id: &LanguageServerId, worktree: &zed::Worktree) -> LspSettings {
+    LspSettings::for_worktree(language_server_id.as_ref(), worktree).unwrap_or_default()
+}
+
+/// Extra server arguments and environment from the user's `binary` settings
+fn binary_args_and_env(settings: &LspSettings) -> (Vec<String>, Vec<(String, String)>) {
+    match &settings.binary {
+        Some(binary) => (
+            binary.arguments.clone().unwrap_or_default(),
+            binary
+                .env
+                .clone()
+                .map(|env| env.into_iter().collect())
+                .unwrap_or_default(),
+        ),
+        None => (vec![], vec![]),
+    }
+}
+
+/// Read the extension's own options field by field, so one mistyped key doesn't reset the rest
+fn laravel_settings(settings: &LspSettings) -> LaravelSettings {
+    let mut laravel = LaravelSettings::default();
+    let Some(serde_json::Value::Object(user)) = &settings.settings else {
+        return laravel;
+    };
+    if let Some(confirm_destructive) = user.get("confirmDestructive").and_then(setting_bool) {
+        laravel.confirm_destructive = confirm_destructive;
+    }
+    laravel
+}
+
+/// A boolean setting, also accepting the strings `"true"` and `"false"`
+fn setting_bool(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(value) => Some(*value),
+        serde_json::Value::String(value) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Server initialization options, with the user's options layered over the defaults
+fn initialization_options(settings: &LspSettings, workspace_path: String) -> serde_json::Value {
+    let mut options = serde_json::json!({
+        "workspacePath": workspace_path,
+    });
+    if let (Some(options), Some(serde_json::Value::Object(user))) =
+        (options.as_object_mut(), &settings.initialization_options)
+    {
+        for (key, value) in user {
+            options.insert(key.clone(), value.clone());
+        }
+    }
+    options
+}
+
+/// Subcommands that drop or roll back data
+const DESTRUCTIVE_ACTIONS: &[&str] = &["fresh", "reset", "refresh", "rollback", "wipe"];
+
+/// Whether choosing a subcommand completion should run it straight away
+fn run_on_complete(action: &str, confirm_destructive: bool) -> bool {
+    !(confirm_destructive && DESTRUCTIVE_ACTIONS.contains(&action))
+}
+
+/// HTTP verbs accepted by `route:list --method`
+const HTTP_VERBS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "HEAD"];
+
+/// Whether `method` is a real HTTP verb, ignoring case
+fn is_http_verb(method: &str) -> bool {
+    HTTP_VERBS
+        .iter()
+        .any(|verb| verb.eq_ignore_ascii_case(method))
+}
+
+/// Build an artisan command line, passing through only the flags the command understands.
+///
+/// Flags are matched by name, so `--hours` in `flags` also accepts `--hours=48`.
+fn artisan_command(command: &str, args: &[String], flags: &[&str]) -> String {
+    let mut cmd = format!("php artisan {}", command);
+    for arg in args {
+        let name = arg.split('=').next().unwrap_or(arg);
+        if flags.contains(&name) {
+            cmd.push(' ');
+            cmd.push_str(arg);
+        }
+    }
+    cmd
+}
+
+/// Convert a dot-notation view name to its Blade template path
+fn view_path(name: &str) -> String {
+    format!("resources/views/{}.blade.php", name.replace('.', "/"))
+}
+
+/// Convert a StudlyCase name to kebab-case (`SendEmails` → `send-emails`)
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
+/// Lowercase the first character, as PHP's `lcfirst` does (`BlogPost` → `blogPost`)
+fn lcfirst(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Class and view paths for a Livewire component, accepting `Admin/Counter` or `admin.counter`
+fn livewire_paths(name: &str) -> (String, String) {
+    let segments: Vec<&str> = name.split(['/', '.']).filter(|s| !s.is_empty()).collect();
+
+    let class_path = segments
+        .iter()
+        .map(|segment| {
+            segment
+                .split(['-', '_'])
+                .map(|part| {
+                    let mut chars = part.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let view_path = class_path
+        .iter()
+        .map(|segment| kebab_case(segment))
+        .collect::<Vec<_>>();
+
+    (
+        format!("app/Livewire/{}.php", class_path.join("/")),
+        format!("resources/views/livewire/{}.blade.php", view_path.join("/")),
+    )
+}
+
+/// Whether `flag` was passed, with or without a `=value`
+fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter()
+        .any(|arg| arg.split('=').next().unwrap_or(arg) == flag)
+}
+
+/// Value of a `--flag=value` argument, if present
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .find_map(|arg| arg.strip_prefix(flag)?.strip_prefix('='))
+}
+
+/// Extra files `make:model` creates alongside the model, from long or combined short flags (`-mf`)
+fn model_companions(args: &[String]) -> Vec<&'static str> {
+    let mut letters = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--all" => letters.push('a'),
+            "--migration" => letters.push('m'),
+            "--factory" => letters.push('f'),
+            "--seed" => letters.push('s'),
+            "--controller" => letters.push('c'),
+            "--resource" => letters.push('r'),
+            "--policy" => letters.push('p'),
+            short if short.starts_with('-') && !short.starts_with("--") => {
+                letters.extend(short.chars().skip(1))
+            }
+            _ => {}
+        }
+    }
+
+    let mut companions = Vec::new();
+    for (letter, companion) in [
+        ('m', "migration"),
+        ('f', "factory"),
+        ('s', "seeder"),
+        ('c', "controller"),
+        ('p', "policy"),
+    ] {
+        if letters.contains(&letter) || letters.contains(&'a') {
+            companions.push(companion);
+        }
+    }
+    if letters.contains(&'r') && !companions.contains(&"controller") {
+        companions.push("controller");
+    }
+    if letters.contains(&'a') {
+        companions.push("form requests");
+    }
+    companions
+}
+
+/// Turn a possibly nested class name (`Admin/DashboardTest` or `Admin\DashboardTest`) into a relative path
+fn nested_class_path(name: &str) -> String {
+    name.replace('\\', "/").trim_matches('/').to_string()
+}
+
+/// Class name without its directory or namespace (`Admin/UserSeeder` → `UserSeeder`)
+fn class_basename(name: &str) -> &str {
+    name.rsplit(['/', '\\']).next().unwrap_or(name)
+}
+
+/// Namespace-relative class name with `\` separators (`Admin/UserObserver` → `Admin\UserObserver`)
+fn class_namespace(name: &str) -> String {
+    name.trim_matches(['/', '\\']).replace('/', "\\")
+}
+
+/// Path of the file generated by `make:<kind> <name>`, when it is predictable
+fn make_target_path(kind: &str, name: &str, args: &[String]) -> Option<String> {
+    let name = &nested_class_path(name);
+    match kind {
+        "test" => {
+            let suite = if has_flag(args, "--unit") {
+                "Unit"
+            } else {
+                "Feature"
+            };
+            Some(format!("tests/{}/{}.php", suite, name))
+        }
+        "notification" => Some(format!("app/Notifications/{}.php", name)),
+        "event" => Some(format!("app/Events/{}.php", name)),
+        "interface" => Some(format!("app/Interfaces/{}.php", name)),
+        "trait" => Some(format!("app/Traits/{}.php", name)),
+        "view" => Some(view_path(name)),
+        "livewire" => Some(livewire_paths(name).0),
+        "seeder" => Some(format!("database/seeders/{}.php", name)),
+        "mail" => Some(format!("app/Mail/{}.php", name)),
+        "controller" => Some(format!("app/Http/Controllers/{}.php", name)),
+        "model" => Some(format!("app/Models/{}.php", name)),
+        "factory" => Some(format!("database/factories/{}.php", name)),
+        "observer" => Some(format!("app/Observers/{}.php", name)),
+        "channel" => Some(format!("app/Broadcasting/{}.php", name)),
+        "policy" => Some(format!("app/Policies/{}.php", name)),
+        "command" => Some(format!("app/Console/Commands/{}.php", name)),
+        "request" => Some(format!("app/Http/Requests/{}.php", name)),
+        "cast" => Some(format!("app/Casts/{}.php", name)),
+        "listener" => Some(format!("app/Listeners/{}.php", name)),
+        "provider" => Some(format!("app/Providers/{}.php", name)),
+        "enum" => Some(format!("app/Enums/{}.php", name)),
+        "middleware" => Some(format!("app/Http/Middleware/{}.php", name)),
+        "job" => Some(format!("app/Jobs/{}.php", name)),
+        "rule" => Some(format!("app/Rules/{}.php", name)),
+        "scope" => Some(format!("app/Models/Scopes/{}.php", name)),
+        "exception" => Some(format!("app/Exceptions/{}.php", name)),
+        "resource" => Some(format!("app/Http/Resources/{}.php", name)),
+        _ => None,
+    }
+}
+
+/// Extra guidance appended to the `/laravel:make` output for specific file types
+fn make_hints(kind: &str, args: &[String]) -> Vec<String> {
+    match kind {
+        "resource" => vec![
+            "Map the model to its JSON shape in `toArray()`:\n\n```php\npublic function toArray(Request $request): array\n{\n    return [\n        'id' => $this->id,\n        'name' => $this->name,\n        'email' => $this->email,\n        'created_at' => $this->created_at,\n    ];\n}\n```".to_string(),
+        ],
+        "exception" => {
+            let mut hints = Vec::new();
+            if has_flag(args, "--report") {
+                hints.push(
+                    "Log or forward the exception in `report()`; return `false` to fall back to the default reporting:\n\n```php\npublic function report(): bool\n{\n    if ($this->needsCustomReporting()) {\n        // ...\n\n        return true;\n    }\n\n    return false;\n}\n```".to_string(),
+                );
+            }
+            if has_flag(args, "--render") {
+                hints.push(
+                    "Build the HTTP response in `render()`:\n\n```php\npublic function render(Request $request): Response\n{\n    return response()->view('errors.custom', status: 500);\n}\n```".to_string(),
+                );
+            }
+            hints
+        }
+        "scope" => vec![
+            "Global scopes constrain every query for the model in `apply()`:\n\n```php\npublic function apply(Builder $builder, Model $model): void\n{\n    $builder->where('is_active', true);\n}\n```\n\nAttach it with `#[ScopedBy([...])]` on the model.".to_string(),
+        ],
+        "rule" => vec![
+            "The rule implements `ValidationRule`, which reports failures through a callback instead of the legacy `Rule` interface's `passes()` and `message()` pair:\n\n```php\npublic function validate(string $attribute, mixed $value, Closure $fail): void\n{\n    if (strtoupper($value) !== $value) {\n        $fail('The :attribute must be uppercase.');\n    }\n}\n```".to_string(),
+        ],
+        "job" => {
+            let mut hints = Vec::new();
+            if has_flag(args, "--batched") {
+                hints.push(
+                    "Batched jobs must use the `Illuminate\\Bus\\Batchable` trait, and should check `$this->batch()->cancelled()` before doing work:\n\n```php\nuse Batchable, Dispatchable, InteractsWithQueue, Queueable, SerializesModels;\n```".to_string(),
+                );
+            }
+            hints.push(
+                "Route the job to a specific queue or connection from its constructor. The `Queueable` trait already declares the `$queue` and `$connection` properties, so set them through its methods rather than redeclaring them:\n\n```php\npublic function __construct()\n{\n    $this->onQueue('emails');\n    $this->onConnection('redis');\n}\n```\n\nOr choose per dispatch with `->onQueue()` / `->onConnection()`.".to_string(),
+            );
+            hints
+        }
+        "middleware" => {
+            let middleware = args
+                .get(1)
+                .map(|name| class_basename(name))
+                .unwrap_or("EnsureTokenIsValid");
+            vec![
+                "Inspect or modify the request before `$next`, and the response after it:\n\n```php\npublic function handle(Request $request, Closure $next): Response\n{\n    if ($request->input('token') !== 'my-secret-token') {\n        return redirect('/home');\n    }\n\n    $response = $next($request);\n    $response->headers->set('X-Frame-Options', 'DENY');\n\n    return $response;\n}\n```".to_string(),
+                format!(
+                    "Register it in `bootstrap/app.php`. Global middleware runs on every request:\n\n```php\n->withMiddleware(function (Middleware $middleware) {{\n    $middleware->append({middleware}::class);\n}})\n```\n\nRoute middleware only runs where it is attached, usually through an alias:\n\n```php\n->withMiddleware(function (Middleware $middleware) {{\n    $middleware->alias(['{alias}' => {middleware}::class]);\n}})\n```",
+                    alias = kebab_case(middleware)
+                ),
+            ]
+        }
+        "enum" => {
+            let case = if has_flag(args, "--string") {
+                Some("case Active = 'active';")
+            } else if has_flag(args, "--int") {
+                Some("case Active = 1;")
+            } else {
+                None
+            };
+            match case {
+                Some(case) => vec![format!(
+                    "Add cases with their backing values:\n\n```php\n{}\n```",
+                    case
+                )],
+                None => vec![],
+            }
+        }
+        "provider" => {
+            let provider = args
+                .get(1)
+                .map(|name| class_namespace(name))
+                .unwrap_or_else(|| "AppServiceProvider".to_string());
+            vec![
+                format!(
+                    "On Laravel 11+, `make:provider` registers the provider in `bootstrap/providers.php` automatically:\n\n```php\nreturn [\n    App\\Providers\\AppServiceProvider::class,\n    App\\Providers\\{}::class,\n];\n```\n\nOn earlier versions, add it to the `providers` array in `config/app.php`.",
+                    provider
+                ),
+                "Bind services into the container in `register()`, and do anything that needs other services (routes, event listeners, view composers) in `boot()`, which runs after every provider is registered:\n\n```php\npublic function register(): void\n{\n    $this->app->singleton(Connection::class, fn ($app) => new Connection(config('riak')));\n}\n\npublic function boot(): void\n{\n    View::composer('profile', ProfileComposer::class);\n}\n```".to_string(),
+            ]
+        }
+        "listener" => {
+            let mut hints = Vec::new();
+            if let Some(event) = flag_value(args, "--event") {
+                let class = if event.contains('\\') {
+                    event.trim_start_matches('\\').to_string()
+                } else {
+                    format!("App\\Events\\{}", class_namespace(event))
+                };
+                hints.push(format!(
+                    "Import the event class in the listener:\n\n```php\nuse {};\n```",
+                    class
+                ));
+            }
+            if has_flag(args, "--queued") {
+                hints.push(
+                    "Queued listeners use the default queue connection. Set the `$connection` property to send them elsewhere:\n\n```php\npublic $connection = 'redis';\n```".to_string(),
+                );
+            }
+            hints
+        }
+        "notification" => vec![
+            "Choose delivery channels in `via()`: `mail`, `database`, `broadcast`, or `slack`:\n\n```php\npublic function via(object $notifiable): array\n{\n    return ['mail', 'database'];\n}\n```".to_string(),
+            "The `database` channel stores notifications in a `notifications` table. Create it with:\n\n```bash\nphp artisan notifications:table\nphp artisan migrate\n```\n\nOn Laravel 11+, the command is `php artisan make:notifications-table`.".to_string(),
+        ],
+        "cast" => {
+            // Inbound casts only have `set()`, since reads return the raw attribute
+            let set_stub = "public function set(Model $model, string $key, mixed $value, array $attributes): mixed\n{\n    return $value;\n}";
+            if has_flag(args, "--inbound") {
+                vec![
+                    "This cast implements `CastsInboundAttributes`, so it only transforms values being set on the model; reads return the raw attribute.".to_string(),
+                    format!("Transform incoming values in `set()`:\n\n```php\n{}\n```", set_stub),
+                ]
+            } else {
+                vec![
+                    "This cast implements `CastsAttributes`, transforming values both when reading from and writing to the model. Pass `--inbound` for a `CastsInboundAttributes` cast that only transforms values being set.".to_string(),
+                    format!(
+                        "Implement both directions:\n\n```php\npublic function get(Model $model, string $key, mixed $value, array $attributes): mixed\n{{\n    return $value;\n}}\n\n{}\n```",
+                        set_stub
+                    ),
+                ]
+            }
+        }
+        "request" => vec![
+            "`authorize()` returns `false` by default, which rejects every request with a 403. Return `true` for open endpoints:\n\n```php\npublic function authorize(): bool\n{\n    return true;\n}\n```".to_string(),
+            "Define the validation rules in `rules()`:\n\n```php\npublic function rules(): array\n{\n    return ['field' => 'required'];\n}\n```".to_string(),
+        ],
+        "command" => match args.get(1) {
+            Some(name) => {
+                // make:command defaults the signature to `app:<kebab-class-basename>`
+                let signature = flag_value(args, "--command")
+                    .map(|signature| signature.to_string())
+                    .unwrap_or_else(|| format!("app:{}", kebab_case(class_basename(name))));
+                vec![
+                    format!(
+                        "Declare arguments and options in the signature, then read them in `handle()`:\n\n```php\nprotected $signature = '{signature} {{user}} {{--queue}}';\n\npublic function handle(): int\n{{\n    $userId = $this->argument('user');\n    $queue = $this->option('queue');\n\n    $this->info(\"Processing user {{$userId}}\");\n\n    return self::SUCCESS;\n}}\n```"
+                    ),
+                    format!(
+                        "Schedule the command in `routes/console.php`:\n\n```php\nuse Illuminate\\Support\\Facades\\Schedule;\n\nSchedule::command('{signature}')->daily();\n```\n\nOn Laravel 10 and earlier, add `$schedule->command('{signature}')->daily();` to the `schedule()` method in `app/Console/Kernel.php`."
+                    ),
+                ]
+            }
+            None => vec![],
+        },
+        "policy" => vec![
+            "Pass `--model` to generate the standard methods that pair with resource controller actions: `viewAny`, `view`, `create`, `update`, `delete`, `restore`, and `forceDelete`.".to_string(),
+            "Policies deny guests by default. To let unauthenticated users through a check, make the user parameter nullable:\n\n```php\npublic function view(?User $user, Post $post): bool\n```".to_string(),
+        ],
+        "channel" => {
+            let name = args.get(1).map(|s| s.as_str()).unwrap_or("OrderChannel");
+            vec![
+                format!(
+                    "Private channels return `true` from `join()` to authorize a user; presence channels return an array of user data instead, which is shared with everyone on the channel. Authorize the channel in `routes/channels.php`:\n\n```php\nuse App\\Broadcasting\\{namespace};\n\nBroadcast::channel('orders.{{order}}', {channel}::class);\n```",
+                    namespace = class_namespace(name),
+                    channel = class_basename(name)
+                ),
+                "Put the authorization logic in `join()`, which receives the user and any route parameters:\n\n```php\npublic function join(User $user, Order $order): array|bool\n{\n    return $user->id === $order->user_id;\n}\n```".to_string(),
+            ]
+        }
+        "observer" => {
+            let mut hints = Vec::new();
+            if let (Some(name), Some(model)) = (args.get(1), flag_value(args, "--model")) {
+                let model = class_basename(model);
+                let observer = class_basename(name);
+                hints.push(format!(
+                    "Register the observer on the model with the `#[ObservedBy]` attribute:\n\n```php\nuse App\\Observers\\{namespace};\nuse Illuminate\\Database\\Eloquent\\Attributes\\ObservedBy;\n\n#[ObservedBy([{observer}::class])]\nclass {model} extends Model\n```\n\nOr in the `boot()` method of a service provider:\n\n```php\n{model}::observe({observer}::class);\n```",
+                    namespace = class_namespace(name)
+                ));
+            }
+            hints.push(
+                "Observers can handle these model events: `creating`, `created`, `updating`, `updated`, `saving`, `saved`, `deleting`, `deleted`, `restoring`, `restored`, and `forceDeleted`.".to_string(),
+            );
+            hints
+        }
+        "factory" => {
+            let model = flag_value(args, "--model")
+                .or_else(|| args.get(1).and_then(|name| name.strip_suffix("Factory")))
+                .map(class_basename)
+                .filter(|model| !model.is_empty())
+                .unwrap_or("Model");
+            vec![
+                "Fill in `definition()` with fake attribute values:\n\n```php\npublic function definition(): array\n{\n    return [\n        'name' => fake()->name(),\n        'email' => fake()->unique()->safeEmail(),\n    ];\n}\n```".to_string(),
+                format!(
+                    "Create records with the factory:\n\n```php\n{}::factory()->count(10)->create();\n```",
+                    model
+                ),
+                format!(
+                    "Define states as methods that override attributes:\n\n```php\npublic function suspended(): static\n{{\n    return $this->state(fn (array $attributes) => [\n        'account_status' => 'suspended',\n    ]);\n}}\n```\n\nThen use them with `{}::factory()->suspended()->create();`.",
+                    model
+                ),
+            ]
+        }
+        "model" => {
+            let companions = model_companions(args);
+            if companions.is_empty() {
+                vec![]
+            } else {
+                vec![format!(
+                    "Multiple files will be created: the model plus its {}.",
+                    companions.join(", ")
+                )]
+            }
+        }
+        "controller" => {
+            let mut hints = Vec::new();
+            if let Some(model) = flag_value(args, "--model") {
+                let model = class_basename(model);
+                hints.push(format!(
+                    "Route-model binding will type-hint `{model}` in the resource methods, so `show({model} ${var})` receives the model matching the route parameter.",
+                    var = lcfirst(model)
+                ));
+            }
+            if let Some(parent) = flag_value(args, "--parent") {
+                hints.push(format!(
+                    "Each resource method will also receive the parent `{}` model, for use with nested resource routes.",
+                    parent
+                ));
+            }
+            hints
+        }
+        "mail" => {
+            let mut hints = Vec::new();
+            if let Some(view) = flag_value(args, "--markdown") {
+                hints.push(format!(
+                    "The markdown template will be created at `{}`.",
+                    view_path(view)
+                ));
+            }
+            hints.push(
+                "Implement `Illuminate\\Contracts\\Queue\\ShouldQueue` on the mailable to always queue it, even when sent with `Mail::send()`.".to_string(),
+            );
+            hints
+        }
+        "seeder" => {
+            let model = args
+                .get(1)
+                .and_then(|name| class_basename(name).strip_suffix("Seeder"))
+                .filter(|model| !model.is_empty())
+                .unwrap_or("Model");
+            let seeder = args
+                .get(1)
+                .map(|name| class_basename(name))
+                .unwrap_or("ExampleSeeder");
+            vec![
+                format!(
+                    "Use a factory inside `run()` to seed records:\n\n```php\n{}::factory()->count(10)->create();\n```",
+                    model
+                ),
+                format!(
+                    "Register the seeder in `DatabaseSeeder::run()` so `db:seed` picks it up:\n\n```php\n$this->call({}::class);\n```",
+                    seeder
+                ),
+            ]
+        }
+        "test" => {
+            if has_flag(args, "--pest") {
+                let suite = if has_flag(args, "--unit") { "Unit" } else { "Feature" };
+                vec![
+                    format!(
+                        "This Pest test lands in the {} suite. Run the same test against several inputs with `->with()` datasets:\n\n```php\nit('accepts valid emails', function (string $email) {{\n    // ...\n}})->with(['taylor@laravel.com', 'abigail@laravel.com']);\n```",
+                        suite
+                    ),
+                    "Tag tests with `->group()` and run a subset with `php artisan test --group=billing`:\n\n```php\nit('charges the card', function () {\n    // ...\n})->group('billing');\n```".to_string(),
+                ]
+            } else {
+                vec![
+                    "Tag tests with the `#[Group]` attribute (or a `@group` docblock annotation on older PHPUnit) and run a subset with `php artisan test --group=billing`:\n\n```php\nuse PHPUnit\\Framework\\Attributes\\Group;\n\n#[Group('billing')]\npublic function test_charges_the_card(): void\n```".to_string(),
+                ]
+            }
+        }
+        "livewire" => match args.get(1) {
+            Some(name) => vec![format!(
+                "The component view will be created at `{}`.",
+                livewire_paths(name).1
+            )],
+            None => vec![],
+        },
+        "event" => vec![
+            "Broadcastable events should implement `Illuminate\\Contracts\\Broadcasting\\ShouldBroadcast`.".to_string(),
+            "`ShouldBroadcast` events are pushed onto the queue before broadcasting; implement `ShouldBroadcastNow` instead to broadcast synchronously within the current request.".to_string(),
+        ],
+        _ => vec![],
+    }
+}
+
+/// Reject flag combinations artisan would refuse or silently ignore
+fn validate_make_args(args: &[String]) -> Result<()> {
+    let kind = args.first().map(|s| s.as_str()).unwrap_or("");
+    if kind == "controller" && has_flag(args, "--singleton") && has_flag(args, "--resource") {
+        return Err(
+            "--singleton and --resource can't be combined: a singleton controller manages exactly one resource instance".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Build the `/laravel:make` output: the artisan command and the file it generates
+fn make_output(args: &[String]) -> String {
+    let kind = args.first().map(|s| s.as_str()).unwrap_or("");
+    let mut text = format!(
+        "Run this artisan command in your Laravel project:\n\n```bash\nphp artisan make:{}\n```",
+        args.join(" ")
+    );
+
+    match args
+        .get(1)
+        .and_then(|name| make_target_path(kind, name, args))
+    {
+        Some(path) => text.push_str(&format!(
+            "\n\nThis will generate `{}` with the proper boilerplate.",
+            path
+        )),
+        None => text.push_str(
+            "\n\nThis will generate the corresponding Laravel file with the proper boilerplate.",
+        ),
+    }
+
+    for hint in make_hints(kind, args) {
+        text.push_str("\n\n");
+        text.push_str(&hint);
+    }
+
+    text
+}
+
+/// Collapse a multiline tinker expression into a one-liner, using `;` between statements
+fn sanitize_tinker_expression(expr: &str) -> String {
+    expr.lines()
+        .map(|line| line.trim().trim_end_matches(';').trim_end())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Split PHP code into statements on `;`, ignoring semicolons inside string literals
+fn php_statements(script: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in script.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if c == ';' => {
+                statements.push(script[start..i].trim());
+                start = i + 1;
+            }
+            None => {}
+        }
+    }
+    statements.push(script[start..].trim());
+    statements.retain(|statement| !statement.is_empty());
+    statements
+}
+
+/// Echo the value of the last statement as pretty-printed JSON, running earlier statements as-is
+fn tinker_json_expression(expr: &str) -> String {
+    let mut statements: Vec<String> = php_statements(expr)
+        .into_iter()
+        .map(|statement| statement.to_string())
+        .collect();
+    if let Some(last) = statements.pop() {
+        statements.push(format!("echo json_encode({}, JSON_PRETTY_PRINT)", last));
+    }
+    statements.join("; ")
+}
+
+/// Pipe a script into `tinker_cmd` through a quoted heredoc, one statement per line
+fn tinker_heredoc(tinker_cmd: &str, script: &str) -> String {
+    let body = php_statements(script)
+        .into_iter()
+        .map(|statement| format!("{};", statement))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{} <<'PHP'\n{}\nPHP", tinker_cmd, body)
+}
+
+/// Quote a string for a POSIX shell using single quotes
+fn shell_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+/// Rewrite a bare seeder name or `--class <Name>` as `--class=<Name>`, so every spelling gives the same command
+fn normalize_seed_args(args: &[String]) -> Vec<String> {
+    let mut normalized = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--class" {
+            match args.next() {
+                Some(class) => normalized.push(format!("--class={}", class)),
+                None => normalized.push(arg.clone()),
+            }
+        } else if arg.starts_with('-') {
+            normalized.push(arg.clone());
+        } else {
+            normalized.push(format!("--class={}", arg));
+        }
+    }
+    normalized
+}
+
+/// Wrap a shell command in a bash code block
+fn bash_output(cmd: &str) -> zed::SlashCommandOutput {
+    zed::SlashCommandOutput {
+        text: format!("```bash\n{}\n```", cmd),
+        sections: vec![],
+    }
+}
+
+/// Wrap a shell command in a bash code block, followed by an explanatory note
+fn bash_output_with_note(cmd: &str, note: &str) -> zed::SlashCommandOutput {
+    zed::SlashCommandOutput {
+        text: format!("```bash\n{}\n```\n\n{}", cmd, note),
+        sections: vec![],
+    }
+}
+
+/// Render labeled shell steps as consecutive bash blocks, one output section per step
+fn build_sectioned_output(steps: &[(String, String)]) -> zed::SlashCommandOutput {
+    let mut text = String::new();
+    let mut sections = Vec::new();
+
+    for (label, cmd) in steps {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        let start = text.len();
+        text.push_str(&format!("```bash\n{}\n```", cmd));
+        sections.push(zed::SlashCommandOutputSection {
+            range: (start..text.len()).into(),
+            label: label.clone(),
+        });
+    }
+
+    zed::SlashCommandOutput { text, sections }
+}
+
+/// Style an LSP completion by kind, falling back to plain text for unspecialized kinds
+fn completion_label(completion: zed::lsp::Completion) -> Option<zed::CodeLabel> {
+    let label = &completion.label;
+
+    match completion.kind {
+        // Route completions (Value)
+        Some(zed::lsp::CompletionKind::Value) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("string".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // View / Inertia page completions (File)
+        Some(zed::lsp::CompletionKind::File) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("string.special".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Eloquent field completions (Field)
+        Some(zed::lsp::CompletionKind::Field) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("property".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Model / Livewire class completions (Class)
+        Some(zed::lsp::CompletionKind::Class) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("type".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Blade component completions (Module)
+        Some(zed::lsp::CompletionKind::Module) => {
+            Some(zed::CodeLabel {
+                code: format!("x-{}", label),
+                spans: vec![
+                    zed::CodeLabelSpan::literal("x-", Some("tag".into())),
+                    zed::CodeLabelSpan::literal(label, Some("tag".into())),
+                ],
+                // Filter on the component name only; the `x-` prefix is display-only
+                filter_range: (2..label.len() + 2).into(),
+            })
+        }
+        // Snippet completions (Blade directives)
+        Some(zed::lsp::CompletionKind::Snippet) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("keyword".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Validation rule / Middleware completions (EnumMember)
+        Some(zed::lsp::CompletionKind::EnumMember) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("constant".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Relation completions (Reference)
+        Some(zed::lsp::CompletionKind::Reference) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("function".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Method/Scope completions
+        Some(zed::lsp::CompletionKind::Method) => {
+            Some(zed::CodeLabel {
+                code: format!("{}()", label),
+                spans: vec![
+                    zed::CodeLabelSpan::literal(label, Some("function".into())),
+                    zed::CodeLabelSpan::literal("()", Some("punctuation".into())),
+                ],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Gate/Event completions
+        Some(zed::lsp::CompletionKind::Event) => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("string".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        // Any other kind, or none at all, still gets a styled label so filtering behaves consistently
+        _ if !label.is_empty() => {
+            Some(zed::CodeLabel {
+                code: label.clone(),
+                spans: vec![zed::CodeLabelSpan::literal(label, Some("text".into()))],
+                filter_range: (0..label.len()).into(),
+            })
+        }
+        _ => None,
+    }
+}
+
 impl zed::Extension for LaravelExtension {
     fn new() -> Self {
         LaravelExtension {
             server_script_path: None,
+            settings: LaravelSettings::default(),
         }
     }
 
     fn language_server_command(
         &mut self,
         language_server_id: &LanguageServerId,
-        _worktree: &zed::Worktree,
+        worktree: &zed::Worktree,
     ) -> Result<zed::Command> {
+        let settings = lsp_settings(language_server_id, worktree);
         let server_script = self.server_script(language_server_id)?;
+        let (extra_args, env) = binary_args_and_env(&settings);
+
+        let mut args = vec![
+            server_script,
+            "--stdio".to_string(),
+        ];
+        args.extend(extra_args);
 
         Ok(zed::Command {
             command: zed::node_binary_path()?,
-            args: vec![
-                server_script,
-                "--stdio".to_string(),
-            ],
-            env: Default::default(),
+            args,
+            env,
         })
     }
 
     fn language_server_initialization_options(
         &mut self,
-        _language_server_id: &LanguageServerId,
+        language_server_id: &LanguageServerId,
         worktree: &zed::Worktree,
     ) -> Result<Option<serde_json::Value>> {
-        Ok(Some(serde_json::json!({
-            "workspacePath": worktree.root_path(),
-        })))
+        let settings = lsp_settings(language_server_id, worktree);
+        self.settings = laravel_settings(&settings);
+        Ok(Some(initialization_options(
+            &settings,
+            worktree.root_path(),
+        )))
     }
 
     fn label_for_completion(
@@ -117,98 +955,7 @@ impl zed::Extension for LaravelExtension {
         _language_server_id: &LanguageServerId,
         completion: zed::lsp::Completion,
     ) -> Option<zed::CodeLabel> {
-        let kind = completion.kind?;
-        let label = &completion.label;
-
-        match kind {
-            // Route completions (Value)
-            zed::lsp::CompletionKind::Value => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("string".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // View / Inertia page completions (File)
-            zed::lsp::CompletionKind::File => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("string.special".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Eloquent field completions (Field)
-            zed::lsp::CompletionKind::Field => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("property".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Model / Livewire class completions (Class)
-            zed::lsp::CompletionKind::Class => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("type".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Blade component completions (Module)
-            zed::lsp::CompletionKind::Module => {
-                Some(zed::CodeLabel {
-                    code: format!("x-{}", label),
-                    spans: vec![
-                        zed::CodeLabelSpan::literal("x-", Some("tag".into())),
-                        zed::CodeLabelSpan::literal(label, Some("tag".into())),
-                    ],
-                    filter_range: (0..label.len() + 2).into(),
-                })
-            }
-            // Snippet completions (Blade directives)
-            zed::lsp::CompletionKind::Snippet => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("keyword".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Validation rule / Middleware completions (EnumMember)
-            zed::lsp::CompletionKind::EnumMember => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("constant".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Relation completions (Reference)
-            zed::lsp::CompletionKind::Reference => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("function".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Method/Scope completions
-            zed::lsp::CompletionKind::Method => {
-                Some(zed::CodeLabel {
-                    code: format!("{}()", label),
-                    spans: vec![
-                        zed::CodeLabelSpan::literal(label, Some("function".into())),
-                        zed::CodeLabelSpan::literal("()", Some("punctuation".into())),
-                    ],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            // Gate/Event completions
-            zed::lsp::CompletionKind::Event => {
-                Some(zed::CodeLabel {
-                    code: label.clone(),
-                    spans: vec![zed::CodeLabelSpan::literal(label, Some("string".into()))],
-                    filter_range: (0..label.len()).into(),
-                })
-            }
-            _ => None,
-        }
+        completion_label(completion)
     }
 
     fn run_slash_command(
@@ -227,83 +974,458 @@ impl zed::Extension for LaravelExtension {
                     });
                 }
 
+                validate_make_args(&_args)?;
                 Ok(zed::SlashCommandOutput {
-                    text: format!(
-                        "Run this artisan command in your Laravel project:\n\n```bash\nphp artisan make:{}\n```\n\nThis will generate the corresponding Laravel file with the proper boilerplate.",
-                        args_str
-                    ),
+                    text: make_output(&_args),
                     sections: vec![],
                 })
             }
             "laravel:routes" => {
+                if let Some(method) = flag_value(&_args, "--method") {
+                    if !is_http_verb(method) {
+                        return Err(format!(
+                            "Invalid --method value `{}`: expected one of {}",
+                            method,
+                            HTTP_VERBS.join(", ")
+                        ));
+                    }
+                }
+                let cmd = artisan_command(
+                    "route:list",
+                    &_args,
+                    &["--json", "--sort", "--except-path", "--method", "--name"],
+                );
+                let mut text = format!(
+                    "Run this command to see all registered routes:\n\n```bash\n{}\n```",
+                    cmd
+                );
+                if has_flag(&_args, "--json") {
+                    text.push_str(&format!(
+                        "\n\nThe JSON output can be piped to `jq` for scripting:\n\n```bash\n{} | jq '.[].uri'\n```",
+                        cmd
+                    ));
+                }
                 Ok(zed::SlashCommandOutput {
-                    text: "Run this command to see all registered routes:\n\n```bash\nphp artisan route:list\n```".to_string(),
+                    text,
                     sections: vec![],
                 })
             }
             "laravel:migrate" => {
                 let action = _args.first().map(|s| s.as_str()).unwrap_or("status");
+                let rest = _args.get(1..).unwrap_or(&[]);
                 let cmd = match action {
-                    "fresh" => "php artisan migrate:fresh",
-                    "rollback" => "php artisan migrate:rollback",
-                    "reset" => "php artisan migrate:reset",
-                    "status" => "php artisan migrate:status",
-                    _ => "php artisan migrate",
+                    "fresh" => artisan_command("migrate:fresh", rest, &[]),
+                    "rollback" => artisan_command("migrate:rollback", rest, &["--step", "--batch"]),
+                    "reset" => artisan_command("migrate:reset", rest, &[]),
+                    "refresh" => artisan_command("migrate:refresh", rest, &["--seed", "--step"]),
+                    "install" => artisan_command("migrate:install", rest, &[]),
+                    "status" => artisan_command("migrate:status", rest, &["--pending", "--path"]),
+                    "run" => artisan_command("migrate", rest, &["--pretend"]),
+                    _ => artisan_command("migrate", &_args, &["--pretend"]),
                 };
-                Ok(zed::SlashCommandOutput {
-                    text: format!("```bash\n{}\n```", cmd),
-                    sections: vec![],
-                })
+                Ok(bash_output(&cmd))
             }
-            _ => Ok(zed::SlashCommandOutput {
-                text: format!("Unknown command: {}", command.name),
-                sections: vec![],
-            }),
-        }
-    }
-
-    fn complete_slash_command_argument(
-        &self,
-        command: zed::SlashCommand,
-        _args: Vec<String>,
-    ) -> Result<Vec<zed::SlashCommandArgumentCompletion>> {
-        match command.name.as_str() {
-            "laravel:make" => {
-                if _args.is_empty() {
-                    Ok(vec![
-                        zed::SlashCommandArgumentCompletion {
-                            label: "model".to_string(),
-                            new_text: "model ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "controller".to_string(),
-                            new_text: "controller ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "migration".to_string(),
-                            new_text: "migration ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "request".to_string(),
-                            new_text: "request ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "resource".to_string(),
-                            new_text: "resource ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "middleware".to_string(),
-                            new_text: "middleware ".to_string(),
-                            run_command: false,
-                        },
-                        zed::SlashCommandArgumentCompletion {
-                            label: "seeder".to_string(),
-                            new_text: "seeder ".to_string(),
+            "laravel:db" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("show");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "show" => Some(artisan_command("db:show", rest, &["--counts", "--views", "--database"])),
+                    "table" => rest.first().map(|table| format!("php artisan db:table {}", table)),
+                    "seed" => Some(artisan_command(
+                        "db:seed",
+                        &normalize_seed_args(rest),
+                        &["--class", "--force"],
+                    )),
+                    "wipe" => Some(artisan_command("db:wipe", rest, &["--drop-views", "--drop-types"])),
+                    "monitor" => Some(artisan_command("db:monitor", rest, &["--databases", "--max"])),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:db <action> [options]\n\nExamples:\n  /laravel:db show\n  /laravel:db show --counts --views\n  /laravel:db table users\n  /laravel:db seed --class=UserSeeder --force\n  /laravel:db wipe --drop-views\n  /laravel:db monitor --databases=mysql --max=100".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:queue" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "work" => Some(artisan_command(
+                        "queue:work",
+                        rest,
+                        &[
+                            "--queue",
+                            "--tries",
+                            "--timeout",
+                            "--once",
+                            "--stop-when-empty",
+                            "--memory",
+                            "--sleep",
+                        ],
+                    )),
+                    "listen" => Some(artisan_command(
+                        "queue:listen",
+                        rest,
+                        &["--queue", "--tries", "--timeout"],
+                    )),
+                    "clear" => Some(artisan_command("queue:clear", rest, &["--queue"])),
+                    "retry-batch" => rest
+                        .first()
+                        .map(|id| format!("php artisan queue:retry-batch {}", id)),
+                    "prune-failed" => {
+                        let hours = rest
+                            .iter()
+                            .find_map(|arg| arg.strip_prefix("--hours="))
+                            .unwrap_or("24");
+                        Some(format!("php artisan queue:prune-failed --hours={}", hours))
+                    }
+                    _ => None,
+                };
+                let note = match action {
+                    "listen" => Some("Unlike `queue:work`, `queue:listen` boots the application for every job, so code changes are picked up without a restart at the cost of throughput. Use it in development only."),
+                    _ => None,
+                };
+                match (cmd, note) {
+                    (Some(cmd), Some(note)) => Ok(bash_output_with_note(&cmd, note)),
+                    (Some(cmd), None) => Ok(bash_output(&cmd)),
+                    (None, _) => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:queue <action> [options]\n\nExamples:\n  /laravel:queue work --queue=emails --tries=3\n  /laravel:queue listen --queue=emails\n  /laravel:queue clear --queue=emails\n  /laravel:queue retry-batch 9a1b2c3d-...\n  /laravel:queue prune-failed\n  /laravel:queue prune-failed --hours=48".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:schedule" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "list" => Some(artisan_command("schedule:list", rest, &["--timezone"])),
+                    "run" => Some("php artisan schedule:run".to_string()),
+                    "work" => Some("php artisan schedule:work".to_string()),
+                    "interrupt" => Some("php artisan schedule:interrupt".to_string()),
+                    _ => None,
+                };
+                let note = match action {
+                    "run" => Some("`schedule:run` executes every task that is due right now, once, and exits. This is what the production cron entry calls each minute; unlike `schedule:work`, it doesn't keep running."),
+                    "work" => Some("`schedule:work` runs the scheduler in the foreground, checking for due tasks every minute, which is handy for local development. In production, use a single cron entry instead:\n\n```bash\n* * * * * cd /path-to-your-project && php artisan schedule:run >> /dev/null 2>&1\n```"),
+                    _ => None,
+                };
+                match (cmd, note) {
+                    (Some(cmd), Some(note)) => Ok(bash_output_with_note(&cmd, note)),
+                    (Some(cmd), None) => Ok(bash_output(&cmd)),
+                    (None, _) => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:schedule <action> [options]\n\nExamples:\n  /laravel:schedule list --timezone=America/New_York\n  /laravel:schedule run\n  /laravel:schedule work\n  /laravel:schedule interrupt".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:config" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "publish" => match rest.first().map(|s| s.as_str()) {
+                        Some("--all") => Some("php artisan config:publish --all".to_string()),
+                        Some(name) => Some(format!("php artisan config:publish {}", name)),
+                        None => None,
+                    },
+                    "cache" => Some("php artisan config:cache".to_string()),
+                    _ => None,
+                };
+                let note = match action {
+                    "cache" => Some("Config files that contain closures can't be serialized, so caching will fail until they are replaced with invokable classes or plain values. Once cached, `env()` calls outside config files return `null`."),
+                    _ => None,
+                };
+                match (cmd, note) {
+                    (Some(cmd), Some(note)) => Ok(bash_output_with_note(&cmd, note)),
+                    (Some(cmd), None) => Ok(bash_output(&cmd)),
+                    (None, _) => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:config <action> [options]\n\nExamples:\n  /laravel:config publish cors\n  /laravel:config publish --all\n  /laravel:config cache".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:model" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "prune" => Some(artisan_command("model:prune", rest, &["--model", "--pretend"])),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:model <action> [options]\n\nExamples:\n  /laravel:model prune\n  /laravel:model prune --model=App\\Models\\Flight --pretend".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:vendor" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "publish" => Some(artisan_command(
+                        "vendor:publish",
+                        rest,
+                        &["--tag", "--provider", "--force"],
+                    )),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:vendor publish [options]\n\nExamples:\n  /laravel:vendor publish --tag=laravel-assets --force\n  /laravel:vendor publish --provider=\"Laravel\\Telescope\\TelescopeServiceProvider\"".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:auth" => {
+                let steps = match _args.first().map(|s| s.as_str()) {
+                    Some("breeze") => vec![
+                        ("Install package", "composer require laravel/breeze --dev"),
+                        ("Run installer", "php artisan breeze:install"),
+                        ("Build assets", "npm install && npm run build"),
+                    ],
+                    Some("jetstream") => vec![
+                        ("Install package", "composer require laravel/jetstream"),
+                        ("Run installer", "php artisan jetstream:install livewire"),
+                        ("Build assets", "npm install && npm run build"),
+                        ("Run migrations", "php artisan migrate"),
+                    ],
+                    Some("ui") => vec![
+                        ("Install package", "composer require laravel/ui"),
+                        ("Run installer", "php artisan ui bootstrap --auth"),
+                        ("Build assets", "npm install && npm run build"),
+                    ],
+                    _ => {
+                        return Ok(zed::SlashCommandOutput {
+                            text: "Usage: /laravel:auth <starter>\n\nStarters:\n  breeze     Minimal auth scaffolding (Blade, Livewire, Inertia)\n  jetstream  Full-featured auth with teams and 2FA\n  ui         Legacy Bootstrap auth scaffolding".to_string(),
+                            sections: vec![],
+                        });
+                    }
+                };
+                let steps: Vec<(String, String)> = steps
+                    .into_iter()
+                    .map(|(label, cmd)| (label.to_string(), cmd.to_string()))
+                    .collect();
+                Ok(build_sectioned_output(&steps))
+            }
+            "laravel:cache" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    // cache:forget takes the store as a positional argument, not a flag
+                    "forget" => rest.first().map(|key| {
+                        match rest.get(1).map(|s| s.strip_prefix("--store=").unwrap_or(s)) {
+                            Some(store) => format!("php artisan cache:forget {} {}", key, store),
+                            None => format!("php artisan cache:forget {}", key),
+                        }
+                    }),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:cache <action> [options]\n\nExamples:\n  /laravel:cache forget users.count\n  /laravel:cache forget users.count --store=redis".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:pennant" => {
+                let cmd = match _args.first().map(|s| s.as_str()) {
+                    Some("purge") => match _args.get(1) {
+                        Some(feature) => Some(format!("php artisan pennant:purge {}", feature)),
+                        None => Some("php artisan pennant:purge".to_string()),
+                    },
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:pennant purge [feature]\n\nExamples:\n  /laravel:pennant purge\n  /laravel:pennant purge new-api".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:telescope" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "prune" => {
+                        let hours = rest
+                            .iter()
+                            .find_map(|arg| arg.strip_prefix("--hours="))
+                            .unwrap_or("24");
+                        Some(format!("php artisan telescope:prune --hours={}", hours))
+                    }
+                    "clear" => Some("php artisan telescope:clear".to_string()),
+                    "publish" => Some("php artisan telescope:publish".to_string()),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:telescope <action> [options]\n\nExamples:\n  /laravel:telescope prune --hours=48\n  /laravel:telescope clear\n  /laravel:telescope publish".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:scout" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let cmd = match action {
+                    "import" | "flush" => _args
+                        .get(1)
+                        .map(|model| format!("php artisan scout:{} \"{}\"", action, model)),
+                    "sync-index-settings" => {
+                        Some("php artisan scout:sync-index-settings".to_string())
+                    }
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:scout <action> [model]\n\nExamples:\n  /laravel:scout import App\\Models\\Post\n  /laravel:scout flush App\\Models\\Post\n  /laravel:scout sync-index-settings".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:octane" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("status");
+                let cmd = match action {
+                    "start" => "php artisan octane:start",
+                    "reload" => "php artisan octane:reload",
+                    "stop" => "php artisan octane:stop",
+                    _ => "php artisan octane:status",
+                };
+                Ok(bash_output(cmd))
+            }
+            "laravel:passport" => {
+                let cmd = match _args.first().map(|s| s.as_str()) {
+                    Some("install") => Some("php artisan passport:install"),
+                    Some("keys") => Some("php artisan passport:keys"),
+                    Some("client") => Some("php artisan passport:client"),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:passport <action>\n\nExamples:\n  /laravel:passport install\n  /laravel:passport keys\n  /laravel:passport client".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:tinker" => {
+                let (flags, expr): (Vec<String>, Vec<String>) =
+                    _args.iter().cloned().partition(|arg| arg.starts_with("--"));
+                let mut expr = sanitize_tinker_expression(&expr.join(" "));
+                // `--json` and `--heredoc` are our own modifiers rather than tinker flags
+                if has_flag(&flags, "--json") && !expr.is_empty() {
+                    expr = tinker_json_expression(&expr);
+                }
+                let mut cmd = artisan_command("tinker", &flags, &["--no-ansi"]);
+                if has_flag(&flags, "--heredoc") && !expr.is_empty() {
+                    cmd = tinker_heredoc(&cmd, &expr);
+                } else if !expr.is_empty() {
+                    cmd.push_str(&format!(" --execute={}", shell_quote(&expr)));
+                }
+                Ok(bash_output(&cmd))
+            }
+            "laravel:env" => {
+                let action = _args.first().map(|s| s.as_str()).unwrap_or("");
+                let rest = _args.get(1..).unwrap_or(&[]);
+                let cmd = match action {
+                    "encrypt" => Some(artisan_command("env:encrypt", rest, &["--key"])),
+                    "decrypt" => Some(artisan_command("env:decrypt", rest, &["--key"])),
+                    _ => None,
+                };
+                match cmd {
+                    Some(cmd) => Ok(bash_output(&cmd)),
+                    None => Ok(zed::SlashCommandOutput {
+                        text: "Usage: /laravel:env <action> [options]\n\nExamples:\n  /laravel:env encrypt\n  /laravel:env decrypt --key=base64:...".to_string(),
+                        sections: vec![],
+                    }),
+                }
+            }
+            "laravel:view" => match _args.first().map(|s| s.as_str()) {
+                Some("clear") => Ok(bash_output_with_note(
+                    "php artisan view:clear",
+                    "This deletes the compiled Blade templates in `storage/framework/views`; they are recompiled on the next request.",
+                )),
+                _ => Ok(zed::SlashCommandOutput {
+                    text: "Usage: /laravel:view <action>\n\nExamples:\n  /laravel:view clear".to_string(),
+                    sections: vec![],
+                }),
+            },
+            "laravel:optimize" => match _args.first().map(|s| s.as_str()) {
+                Some("clear") => Ok(bash_output_with_note(
+                    "php artisan optimize:clear",
+                    "This clears the cached config, events, routes, and views, plus the compiled services and packages files. It also runs `cache:clear`, which flushes the entire application cache store, including Redis if that is your cache driver.",
+                )),
+                _ => Ok(bash_output_with_note(
+                    "php artisan optimize",
+                    "Run this as part of your deploy: it caches the config, events, routes, and views so they aren't rebuilt on every request. Rerun it (or `optimize:clear`) whenever those files change.",
+                )),
+            },
+            "laravel:storage" => match _args.first().map(|s| s.as_str()) {
+                Some("link") => Ok(bash_output(&artisan_command(
+                    "storage:link",
+                    &_args[1..],
+                    &["--relative"],
+                ))),
+                _ => Ok(zed::SlashCommandOutput {
+                    text: "Usage: /laravel:storage <action> [options]\n\nExamples:\n  /laravel:storage link\n  /laravel:storage link --relative".to_string(),
+                    sections: vec![],
+                }),
+            },
+            _ => Ok(zed::SlashCommandOutput {
+                text: format!("Unknown command: {}", command.name),
+                sections: vec![],
+            }),
+        }
+    }
+
+    fn complete_slash_command_argument(
+        &self,
+        command: zed::SlashCommand,
+        _args: Vec<String>,
+    ) -> Result<Vec<zed::SlashCommandArgumentCompletion>> {
+        match command.name.as_str() {
+            "laravel:make" => {
+                if _args.is_empty() {
+                    Ok(vec![
+                        zed::SlashCommandArgumentCompletion {
+                            label: "model".to_string(),
+                            new_text: "model ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "controller".to_string(),
+                            new_text: "controller ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "migration".to_string(),
+                            new_text: "migration ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "request".to_string(),
+                            new_text: "request ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "resource".to_string(),
+                            new_text: "resource ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "middleware".to_string(),
+                            new_text: "middleware ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "seeder".to_string(),
+                            new_text: "seeder ".to_string(),
                             run_command: false,
                         },
                         zed::SlashCommandArgumentCompletion {
@@ -356,13 +1478,169 @@ impl zed::Extension for LaravelExtension {
                             new_text: "test ".to_string(),
                             run_command: false,
                         },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "interface".to_string(),
+                            new_text: "interface ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "trait".to_string(),
+                            new_text: "trait ".to_string(),
+                            run_command: false,
+                        },
+                        zed::SlashCommandArgumentCompletion {
+                            label: "view".to_string(),
+                            new_text: "view ".to_string(),
+                            run_command: false,
+                        },
                     ])
                 } else {
-                    Ok(vec![])
+                    match _args[0].as_str() {
+                        "model" if _args.len() > 2 => Ok([
+                            "--all",
+                            "--migration",
+                            "--factory",
+                            "--seed",
+                            "--controller",
+                            "--resource",
+                            "--policy",
+                        ]
+                        .iter()
+                        .map(|flag| zed::SlashCommandArgumentCompletion {
+                            label: flag.to_string(),
+                            new_text: flag.to_string(),
+                            run_command: false,
+                        })
+                        .collect()),
+                        "controller" if _args.len() > 2 => Ok(vec![
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--resource".to_string(),
+                                new_text: "--resource".to_string(),
+                                run_command: false,
+                            },
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--parent".to_string(),
+                                new_text: "--parent=".to_string(),
+                                run_command: false,
+                            },
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--model".to_string(),
+                                new_text: "--model=".to_string(),
+                                run_command: false,
+                            },
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--singleton".to_string(),
+                                new_text: "--singleton".to_string(),
+                                run_command: false,
+                            },
+                        ]),
+                        "job" if _args.len() > 2 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                            label: "--batched".to_string(),
+                            new_text: "--batched".to_string(),
+                            run_command: false,
+                        }]),
+                        "test" if _args.len() > 2 => Ok(vec![
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--pest".to_string(),
+                                new_text: "--pest".to_string(),
+                                run_command: false,
+                            },
+                            zed::SlashCommandArgumentCompletion {
+                                label: "--unit".to_string(),
+                                new_text: "--unit".to_string(),
+                                run_command: false,
+                            },
+                        ]),
+                        "notification" if _args.len() > 2 => {
+                            Ok(vec![zed::SlashCommandArgumentCompletion {
+                                label: "--markdown".to_string(),
+                                new_text: "--markdown=".to_string(),
+                                run_command: false,
+                            }])
+                        }
+                        _ => Ok(vec![]),
+                    }
                 }
             }
-            "laravel:migrate" => {
-                Ok(vec![
+            "laravel:routes" => {
+                let mut completions = vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--json".to_string(),
+                        new_text: "--json".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--except-path".to_string(),
+                        new_text: "--except-path=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--name".to_string(),
+                        new_text: "--name=".to_string(),
+                        run_command: false,
+                    },
+                ];
+                completions.extend(HTTP_VERBS.iter().map(|verb| {
+                    zed::SlashCommandArgumentCompletion {
+                        label: format!("--method={}", verb),
+                        new_text: format!("--method={}", verb),
+                        run_command: true,
+                    }
+                }));
+                completions.extend(
+                    ["uri", "name", "action", "method", "domain", "middleware"]
+                        .iter()
+                        .map(|column| zed::SlashCommandArgumentCompletion {
+                            label: format!("--sort={}", column),
+                            new_text: format!("--sort={}", column),
+                            run_command: true,
+                        }),
+                );
+                Ok(completions)
+            }
+            "laravel:migrate" => match _args.first().map(|s| s.as_str()) {
+                Some("status") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--pending".to_string(),
+                        new_text: "--pending".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--path".to_string(),
+                        new_text: "--path=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("run") if _args.len() > 1 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "--pretend".to_string(),
+                    new_text: "--pretend".to_string(),
+                    run_command: true,
+                }]),
+                Some("rollback") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--step".to_string(),
+                        new_text: "--step=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--batch".to_string(),
+                        new_text: "--batch=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("refresh") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--seed".to_string(),
+                        new_text: "--seed".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--step".to_string(),
+                        new_text: "--step=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                _ => Ok(vec![
                     zed::SlashCommandArgumentCompletion {
                         label: "run".to_string(),
                         new_text: "run".to_string(),
@@ -371,23 +1649,2007 @@ impl zed::Extension for LaravelExtension {
                     zed::SlashCommandArgumentCompletion {
                         label: "fresh".to_string(),
                         new_text: "fresh".to_string(),
-                        run_command: true,
+                        run_command: run_on_complete("fresh", self.settings.confirm_destructive),
                     },
                     zed::SlashCommandArgumentCompletion {
                         label: "rollback".to_string(),
                         new_text: "rollback".to_string(),
-                        run_command: true,
+                        run_command: run_on_complete("rollback", self.settings.confirm_destructive),
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "refresh".to_string(),
+                        new_text: "refresh".to_string(),
+                        run_command: run_on_complete("refresh", self.settings.confirm_destructive),
                     },
                     zed::SlashCommandArgumentCompletion {
                         label: "status".to_string(),
                         new_text: "status".to_string(),
                         run_command: true,
                     },
-                ])
-            }
-            _ => Ok(vec![]),
-        }
-    }
-}
-
-zed::register_extension!(LaravelExtension);
+                    zed::SlashCommandArgumentCompletion {
+                        label: "install".to_string(),
+                        new_text: "install".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:db" => match _args.first().map(|s| s.as_str()) {
+                Some("show") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--counts".to_string(),
+                        new_text: "--counts".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--views".to_string(),
+                        new_text: "--views".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--database".to_string(),
+                        new_text: "--database=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("seed") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--class".to_string(),
+                        new_text: "--class=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--force".to_string(),
+                        new_text: "--force".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("monitor") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--databases".to_string(),
+                        new_text: "--databases=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--max".to_string(),
+                        new_text: "--max=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("wipe") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--drop-views".to_string(),
+                        new_text: "--drop-views".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--drop-types".to_string(),
+                        new_text: "--drop-types".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                // Table names can't be known without querying the database
+                Some("table") if _args.len() > 1 => Ok(vec![]),
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "show".to_string(),
+                        new_text: "show".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "table".to_string(),
+                        new_text: "table ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "seed".to_string(),
+                        new_text: "seed".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "monitor".to_string(),
+                        new_text: "monitor".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "wipe".to_string(),
+                        new_text: "wipe".to_string(),
+                        run_command: run_on_complete("wipe", self.settings.confirm_destructive),
+                    },
+                ]),
+            },
+            "laravel:queue" => match _args.first().map(|s| s.as_str()) {
+                Some("work") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--queue".to_string(),
+                        new_text: "--queue=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--tries".to_string(),
+                        new_text: "--tries=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--timeout".to_string(),
+                        new_text: "--timeout=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--once".to_string(),
+                        new_text: "--once".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--stop-when-empty".to_string(),
+                        new_text: "--stop-when-empty".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--memory".to_string(),
+                        new_text: "--memory=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--sleep".to_string(),
+                        new_text: "--sleep=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("listen") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--queue".to_string(),
+                        new_text: "--queue=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--tries".to_string(),
+                        new_text: "--tries=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--timeout".to_string(),
+                        new_text: "--timeout=".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                Some("clear") if _args.len() > 1 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "--queue".to_string(),
+                    new_text: "--queue=".to_string(),
+                    run_command: false,
+                }]),
+                // Batch ids are only known at runtime
+                Some("retry-batch") if _args.len() > 1 => Ok(vec![]),
+                Some("prune-failed") if _args.len() > 1 => {
+                    Ok(vec![zed::SlashCommandArgumentCompletion {
+                        label: "--hours".to_string(),
+                        new_text: "--hours=".to_string(),
+                        run_command: false,
+                    }])
+                }
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "work".to_string(),
+                        new_text: "work".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "listen".to_string(),
+                        new_text: "listen".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "clear".to_string(),
+                        new_text: "clear".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "retry-batch".to_string(),
+                        new_text: "retry-batch ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "prune-failed".to_string(),
+                        new_text: "prune-failed".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:schedule" => match _args.first().map(|s| s.as_str()) {
+                Some("list") if _args.len() > 1 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "--timezone".to_string(),
+                    new_text: "--timezone=".to_string(),
+                    run_command: false,
+                }]),
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "list".to_string(),
+                        new_text: "list".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "run".to_string(),
+                        new_text: "run".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "work".to_string(),
+                        new_text: "work".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "interrupt".to_string(),
+                        new_text: "interrupt".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:config" => match _args.first().map(|s| s.as_str()) {
+                Some("publish") if _args.len() > 1 => {
+                    let mut completions = vec![zed::SlashCommandArgumentCompletion {
+                        label: "--all".to_string(),
+                        new_text: "--all".to_string(),
+                        run_command: true,
+                    }];
+                    completions.extend(
+                        [
+                            "app",
+                            "auth",
+                            "broadcasting",
+                            "cache",
+                            "cors",
+                            "database",
+                            "filesystems",
+                            "hashing",
+                            "logging",
+                            "mail",
+                            "queue",
+                            "services",
+                            "session",
+                            "view",
+                        ]
+                        .iter()
+                        .map(|name| zed::SlashCommandArgumentCompletion {
+                            label: name.to_string(),
+                            new_text: name.to_string(),
+                            run_command: true,
+                        }),
+                    );
+                    Ok(completions)
+                }
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "publish".to_string(),
+                        new_text: "publish ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "cache".to_string(),
+                        new_text: "cache".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:model" => match _args.first().map(|s| s.as_str()) {
+                Some("prune") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--model".to_string(),
+                        new_text: "--model=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--pretend".to_string(),
+                        new_text: "--pretend".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                _ => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "prune".to_string(),
+                    new_text: "prune".to_string(),
+                    run_command: true,
+                }]),
+            },
+            "laravel:vendor" => match _args.first().map(|s| s.as_str()) {
+                Some("publish") if _args.len() > 1 => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--tag".to_string(),
+                        new_text: "--tag=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--provider".to_string(),
+                        new_text: "--provider=".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--force".to_string(),
+                        new_text: "--force".to_string(),
+                        run_command: false,
+                    },
+                ]),
+                _ => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "publish".to_string(),
+                    new_text: "publish ".to_string(),
+                    run_command: false,
+                }]),
+            },
+            "laravel:auth" => Ok(vec![
+                zed::SlashCommandArgumentCompletion {
+                    label: "breeze".to_string(),
+                    new_text: "breeze".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "jetstream".to_string(),
+                    new_text: "jetstream".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "ui".to_string(),
+                    new_text: "ui".to_string(),
+                    run_command: true,
+                },
+            ]),
+            "laravel:cache" => match _args.first().map(|s| s.as_str()) {
+                Some("forget") if _args.len() > 2 => {
+                    Ok(vec![zed::SlashCommandArgumentCompletion {
+                        label: "--store".to_string(),
+                        new_text: "--store=".to_string(),
+                        run_command: false,
+                    }])
+                }
+                Some("forget") if _args.len() > 1 => Ok(vec![]),
+                _ => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "forget".to_string(),
+                    new_text: "forget ".to_string(),
+                    run_command: false,
+                }]),
+            },
+            "laravel:pennant" => match _args.first().map(|s| s.as_str()) {
+                // Feature names are defined in application code
+                Some("purge") if _args.len() > 1 => Ok(vec![]),
+                _ => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "purge".to_string(),
+                    new_text: "purge".to_string(),
+                    run_command: true,
+                }]),
+            },
+            "laravel:telescope" => match _args.first().map(|s| s.as_str()) {
+                Some("prune") if _args.len() > 1 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "--hours".to_string(),
+                    new_text: "--hours=".to_string(),
+                    run_command: false,
+                }]),
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "prune".to_string(),
+                        new_text: "prune".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "clear".to_string(),
+                        new_text: "clear".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "publish".to_string(),
+                        new_text: "publish".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:scout" => match _args.first().map(|s| s.as_str()) {
+                Some("import" | "flush") if _args.len() > 1 => Ok(vec![]),
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "import".to_string(),
+                        new_text: "import ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "flush".to_string(),
+                        new_text: "flush ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "sync-index-settings".to_string(),
+                        new_text: "sync-index-settings".to_string(),
+                        run_command: true,
+                    },
+                ]),
+            },
+            "laravel:octane" => Ok(vec![
+                zed::SlashCommandArgumentCompletion {
+                    label: "status".to_string(),
+                    new_text: "status".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "start".to_string(),
+                    new_text: "start".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "reload".to_string(),
+                    new_text: "reload".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "stop".to_string(),
+                    new_text: "stop".to_string(),
+                    run_command: true,
+                },
+            ]),
+            "laravel:passport" => Ok(vec![
+                zed::SlashCommandArgumentCompletion {
+                    label: "install".to_string(),
+                    new_text: "install".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "keys".to_string(),
+                    new_text: "keys".to_string(),
+                    run_command: true,
+                },
+                zed::SlashCommandArgumentCompletion {
+                    label: "client".to_string(),
+                    new_text: "client".to_string(),
+                    run_command: true,
+                },
+            ]),
+            // Only offer modifiers before the expression starts
+            "laravel:tinker" if _args.iter().rev().skip(1).all(|arg| arg.starts_with("--")) => {
+                Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--no-ansi".to_string(),
+                        new_text: "--no-ansi ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--json".to_string(),
+                        new_text: "--json ".to_string(),
+                        run_command: false,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "--heredoc".to_string(),
+                        new_text: "--heredoc ".to_string(),
+                        run_command: false,
+                    },
+                ])
+            }
+            "laravel:env" => match _args.first().map(|s| s.as_str()) {
+                Some("encrypt" | "decrypt") if _args.len() > 1 => {
+                    Ok(vec![zed::SlashCommandArgumentCompletion {
+                        label: "--key".to_string(),
+                        new_text: "--key=".to_string(),
+                        run_command: false,
+                    }])
+                }
+                _ => Ok(vec![
+                    zed::SlashCommandArgumentCompletion {
+                        label: "encrypt".to_string(),
+                        new_text: "encrypt".to_string(),
+                        run_command: true,
+                    },
+                    zed::SlashCommandArgumentCompletion {
+                        label: "decrypt".to_string(),
+                        new_text: "decrypt ".to_string(),
+                        run_command: false,
+                    },
+                ]),
+            },
+            "laravel:view" => Ok(vec![zed::SlashCommandArgumentCompletion {
+                label: "clear".to_string(),
+                new_text: "clear".to_string(),
+                run_command: true,
+            }]),
+            "laravel:optimize" => Ok(vec![zed::SlashCommandArgumentCompletion {
+                label: "clear".to_string(),
+                new_text: "clear".to_string(),
+                run_command: true,
+            }]),
+            "laravel:storage" => match _args.first().map(|s| s.as_str()) {
+                Some("link") if _args.len() > 1 => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "--relative".to_string(),
+                    new_text: "--relative".to_string(),
+                    run_command: true,
+                }]),
+                _ => Ok(vec![zed::SlashCommandArgumentCompletion {
+                    label: "link".to_string(),
+                    new_text: "link".to_string(),
+                    run_command: true,
+                }]),
+            },
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+zed::register_extension!(LaravelExtension);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zed::Extension;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn slash_command(name: &str) -> zed::SlashCommand {
+        zed::SlashCommand {
+            name: name.to_string(),
+            description: String::new(),
+            tooltip_text: String::new(),
+            requires_argument: false,
+        }
+    }
+
+    /// Text produced by `/<name> <args>`
+    fn run(name: &str, list: &[&str]) -> String {
+        LaravelExtension::new()
+            .run_slash_command(slash_command(name), args(list), None)
+            .unwrap()
+            .text
+    }
+
+    /// Whether a completion labeled `label` is offered while typing the last of `args`
+    fn offers(name: &str, list: &[&str], label: &str) -> bool {
+        LaravelExtension::new()
+            .complete_slash_command_argument(slash_command(name), args(list))
+            .unwrap()
+            .iter()
+            .any(|completion| completion.label == label)
+    }
+
+    fn completion(label: &str, kind: Option<zed::lsp::CompletionKind>) -> zed::lsp::Completion {
+        zed::lsp::Completion {
+            label: label.to_string(),
+            label_details: None,
+            detail: None,
+            kind,
+            insert_text_format: None,
+        }
+    }
+
+    fn lsp_settings_with(settings: serde_json::Value) -> LspSettings {
+        LspSettings {
+            settings: Some(settings),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn db_show_plain() {
+        assert_eq!(
+            run("laravel:db", &["show"]),
+            "```bash\nphp artisan db:show\n```"
+        );
+        assert_eq!(run("laravel:db", &[]), "```bash\nphp artisan db:show\n```");
+    }
+
+    #[test]
+    fn db_show_with_counts_and_views() {
+        assert_eq!(
+            run("laravel:db", &["show", "--counts", "--views"]),
+            "```bash\nphp artisan db:show --counts --views\n```"
+        );
+        assert!(offers("laravel:db", &["show", ""], "--counts"));
+        assert!(offers("laravel:db", &["show", ""], "--views"));
+    }
+
+    #[test]
+    fn db_table_with_name() {
+        assert_eq!(
+            run("laravel:db", &["table", "users"]),
+            "```bash\nphp artisan db:table users\n```"
+        );
+        assert!(run("laravel:db", &["table"]).starts_with("Usage: /laravel:db"));
+        assert!(!offers("laravel:db", &["table", ""], "show"));
+    }
+
+    #[test]
+    fn migrate_status_pending() {
+        assert_eq!(
+            run("laravel:migrate", &["status", "--pending"]),
+            "```bash\nphp artisan migrate:status --pending\n```"
+        );
+        assert!(offers("laravel:migrate", &["status", ""], "--pending"));
+    }
+
+    #[test]
+    fn migrate_status_scoped_to_path() {
+        assert_eq!(
+            run(
+                "laravel:migrate",
+                &["status", "--path=database/migrations/tenant"]
+            ),
+            "```bash\nphp artisan migrate:status --path=database/migrations/tenant\n```"
+        );
+        assert!(offers("laravel:migrate", &["status", ""], "--path"));
+    }
+
+    #[test]
+    fn queue_prune_failed_hours() {
+        assert_eq!(
+            run("laravel:queue", &["prune-failed"]),
+            "```bash\nphp artisan queue:prune-failed --hours=24\n```"
+        );
+        assert_eq!(
+            run("laravel:queue", &["prune-failed", "--hours=48"]),
+            "```bash\nphp artisan queue:prune-failed --hours=48\n```"
+        );
+        assert!(offers("laravel:queue", &["prune-failed", ""], "--hours"));
+    }
+
+    #[test]
+    fn queue_work_flag_passthrough() {
+        assert_eq!(
+            run("laravel:queue", &["work", "--queue=emails", "--tries=3"]),
+            "```bash\nphp artisan queue:work --queue=emails --tries=3\n```"
+        );
+        assert_eq!(
+            run(
+                "laravel:queue",
+                &["work", "--once", "--timeout=60", "--stop-when-empty"]
+            ),
+            "```bash\nphp artisan queue:work --once --timeout=60 --stop-when-empty\n```"
+        );
+        for flag in [
+            "--queue",
+            "--tries",
+            "--timeout",
+            "--once",
+            "--stop-when-empty",
+        ] {
+            assert!(offers("laravel:queue", &["work", ""], flag));
+        }
+    }
+
+    #[test]
+    fn make_notification_markdown() {
+        let text = make_output(&args(&[
+            "notification",
+            "InvoicePaid",
+            "--markdown=mail.invoice.paid",
+        ]));
+        assert!(
+            text.contains("php artisan make:notification InvoicePaid --markdown=mail.invoice.paid")
+        );
+        assert!(text.contains("`app/Notifications/InvoicePaid.php`"));
+        assert!(offers(
+            "laravel:make",
+            &["notification", "InvoicePaid", ""],
+            "--markdown"
+        ));
+    }
+
+    #[test]
+    fn make_notification_default() {
+        let text = make_output(&args(&["notification", "InvoicePaid"]));
+        assert!(text.contains("php artisan make:notification InvoicePaid\n"));
+        assert!(text.contains("`app/Notifications/InvoicePaid.php`"));
+    }
+
+    #[test]
+    fn make_event_broadcast_hint() {
+        let text = make_output(&args(&["event", "OrderShipped"]));
+        assert!(text.starts_with(
+            "Run this artisan command in your Laravel project:\n\n```bash\nphp artisan make:event OrderShipped\n```"
+        ));
+        assert!(text.contains("`app/Events/OrderShipped.php`"));
+        assert!(text.contains("`Illuminate\\Contracts\\Broadcasting\\ShouldBroadcast`"));
+    }
+
+    #[test]
+    fn schedule_interrupt() {
+        assert_eq!(
+            run("laravel:schedule", &["interrupt"]),
+            "```bash\nphp artisan schedule:interrupt\n```"
+        );
+        assert!(offers("laravel:schedule", &[""], "interrupt"));
+    }
+
+    #[test]
+    fn install_error_from_npm_output() {
+        assert_eq!(
+            install_error_for_line("npm ERR! code E404").as_deref(),
+            Some("code E404")
+        );
+        assert_eq!(
+            install_error_for_line("npm error network request failed").as_deref(),
+            Some("network request failed")
+        );
+        assert_eq!(install_error_for_line("npm ERR!"), None);
+        assert_eq!(install_error_for_line("added 42 packages in 3s"), None);
+    }
+
+    #[test]
+    fn install_error_from_pnpm_output() {
+        assert_eq!(
+            install_error_for_line(
+                " ERR_PNPM_FETCH_404  GET https://registry.npmjs.org/nope: Not Found - 404"
+            )
+            .as_deref(),
+            Some("ERR_PNPM_FETCH_404  GET https://registry.npmjs.org/nope: Not Found - 404")
+        );
+        assert_eq!(
+            install_error_for_line("Progress: resolved 120, reused 98, downloaded 22, added 0"),
+            None
+        );
+    }
+
+    #[test]
+    fn make_interface_payable() {
+        let text = make_output(&args(&["interface", "Payable"]));
+        assert!(text.contains("php artisan make:interface Payable\n"));
+        assert!(text.contains("`app/Interfaces/Payable.php`"));
+        assert!(offers("laravel:make", &[], "interface"));
+    }
+
+    #[test]
+    fn make_trait_has_uuid() {
+        let text = make_output(&args(&["trait", "HasUuid"]));
+        assert!(text.contains("php artisan make:trait HasUuid\n"));
+        assert!(text.contains("`app/Traits/HasUuid.php`"));
+        assert!(offers("laravel:make", &[], "trait"));
+    }
+
+    #[test]
+    fn make_view_flat_name() {
+        assert_eq!(view_path("welcome"), "resources/views/welcome.blade.php");
+        let text = make_output(&args(&["view", "welcome"]));
+        assert!(text.contains("php artisan make:view welcome\n"));
+        assert!(text.contains("`resources/views/welcome.blade.php`"));
+    }
+
+    #[test]
+    fn make_view_dotted_name() {
+        assert_eq!(
+            view_path("admin.dashboard"),
+            "resources/views/admin/dashboard.blade.php"
+        );
+        let text = make_output(&args(&["view", "admin.dashboard"]));
+        assert!(text.contains("`resources/views/admin/dashboard.blade.php`"));
+    }
+
+    #[test]
+    fn livewire_nested_component_paths() {
+        let expected = (
+            "app/Livewire/Admin/Counter.php".to_string(),
+            "resources/views/livewire/admin/counter.blade.php".to_string(),
+        );
+        assert_eq!(livewire_paths("Admin/Counter"), expected);
+        assert_eq!(livewire_paths("admin.counter"), expected);
+
+        let text = make_output(&args(&["livewire", "Admin/Counter"]));
+        assert!(text.contains("`app/Livewire/Admin/Counter.php`"));
+        assert!(text.contains("`resources/views/livewire/admin/counter.blade.php`"));
+    }
+
+    #[test]
+    fn livewire_flat_component_paths() {
+        assert_eq!(
+            livewire_paths("ShowPosts"),
+            (
+                "app/Livewire/ShowPosts.php".to_string(),
+                "resources/views/livewire/show-posts.blade.php".to_string(),
+            )
+        );
+        assert_eq!(livewire_paths("show-posts").0, "app/Livewire/ShowPosts.php");
+    }
+
+    #[test]
+    fn config_publish_named() {
+        assert_eq!(
+            run("laravel:config", &["publish", "cors"]),
+            "```bash\nphp artisan config:publish cors\n```"
+        );
+        assert!(offers("laravel:config", &["publish", ""], "cors"));
+    }
+
+    #[test]
+    fn config_publish_all() {
+        assert_eq!(
+            run("laravel:config", &["publish", "--all"]),
+            "```bash\nphp artisan config:publish --all\n```"
+        );
+        assert!(offers("laravel:config", &["publish", ""], "--all"));
+    }
+
+    #[test]
+    fn unhandled_completion_kind_gets_plain_label() {
+        let label = completion_label(completion(
+            "orderBy",
+            Some(zed::lsp::CompletionKind::Keyword),
+        ))
+        .expect("unhandled kinds should still be labeled");
+        assert_eq!(label.code, "orderBy");
+        assert_eq!((label.filter_range.start, label.filter_range.end), (0, 7));
+
+        let label = completion_label(completion("orderBy", None))
+            .expect("completions without a kind should still be labeled");
+        assert_eq!(label.code, "orderBy");
+
+        assert!(completion_label(completion("", None)).is_none());
+    }
+
+    #[test]
+    fn make_test_pest_dataset_note() {
+        let pest = make_output(&args(&["test", "UserTest", "--pest"]));
+        assert!(pest.contains("`->with()` datasets"));
+        assert!(pest.contains("Feature suite"));
+        assert!(
+            make_output(&args(&["test", "UserTest", "--pest", "--unit"])).contains("Unit suite")
+        );
+
+        let phpunit = make_output(&args(&["test", "UserTest"]));
+        assert!(!phpunit.contains("->with()"));
+    }
+
+    #[test]
+    fn model_prune_forms() {
+        assert_eq!(
+            run("laravel:model", &["prune"]),
+            "```bash\nphp artisan model:prune\n```"
+        );
+        assert_eq!(
+            run("laravel:model", &["prune", "--model=App\\Models\\Flight"]),
+            "```bash\nphp artisan model:prune --model=App\\Models\\Flight\n```"
+        );
+        assert_eq!(
+            run("laravel:model", &["prune", "--pretend"]),
+            "```bash\nphp artisan model:prune --pretend\n```"
+        );
+        assert!(offers("laravel:model", &["prune", ""], "--model"));
+        assert!(offers("laravel:model", &["prune", ""], "--pretend"));
+    }
+
+    #[test]
+    fn vendor_publish_force_with_tag() {
+        assert_eq!(
+            run(
+                "laravel:vendor",
+                &["publish", "--tag=laravel-assets", "--force"]
+            ),
+            "```bash\nphp artisan vendor:publish --tag=laravel-assets --force\n```"
+        );
+        assert!(offers("laravel:vendor", &["publish", ""], "--force"));
+    }
+
+    #[test]
+    fn auth_breeze_steps() {
+        let text = run("laravel:auth", &["breeze"]);
+        assert!(text.contains("composer require laravel/breeze --dev"));
+        assert!(text.contains("php artisan breeze:install"));
+    }
+
+    #[test]
+    fn auth_jetstream_steps() {
+        let text = run("laravel:auth", &["jetstream"]);
+        assert!(text.contains("composer require laravel/jetstream"));
+        assert!(text.contains("php artisan jetstream:install livewire"));
+        assert!(run("laravel:auth", &[]).starts_with("Usage: /laravel:auth"));
+    }
+
+    #[test]
+    fn migrate_refresh_forms() {
+        assert_eq!(
+            run("laravel:migrate", &["refresh"]),
+            "```bash\nphp artisan migrate:refresh\n```"
+        );
+        assert_eq!(
+            run("laravel:migrate", &["refresh", "--seed"]),
+            "```bash\nphp artisan migrate:refresh --seed\n```"
+        );
+        assert!(offers("laravel:migrate", &["refresh", ""], "--seed"));
+        assert!(offers("laravel:migrate", &["refresh", ""], "--step"));
+    }
+
+    #[test]
+    fn cache_forget_key_and_store() {
+        assert_eq!(
+            run("laravel:cache", &["forget", "users.count"]),
+            "```bash\nphp artisan cache:forget users.count\n```"
+        );
+        assert_eq!(
+            run("laravel:cache", &["forget", "users.count", "--store=redis"]),
+            "```bash\nphp artisan cache:forget users.count redis\n```"
+        );
+        assert!(run("laravel:cache", &["forget"]).starts_with("Usage: /laravel:cache"));
+        assert!(offers(
+            "laravel:cache",
+            &["forget", "users.count", ""],
+            "--store"
+        ));
+    }
+
+    #[test]
+    fn make_seeder_factory_snippet() {
+        let text = make_output(&args(&["seeder", "UserSeeder"]));
+        assert!(text.contains("`database/seeders/UserSeeder.php`"));
+        assert!(text.contains("User::factory()->count(10)->create();"));
+
+        let nested = make_output(&args(&["seeder", "Admin/UserSeeder"]));
+        assert!(nested.contains("`database/seeders/Admin/UserSeeder.php`"));
+        assert!(nested.contains("\nUser::factory()->count(10)->create();"));
+        assert!(!nested.contains("Admin/User::factory()"));
+    }
+
+    #[test]
+    fn pennant_purge_plain_and_scoped() {
+        assert_eq!(
+            run("laravel:pennant", &["purge"]),
+            "```bash\nphp artisan pennant:purge\n```"
+        );
+        assert_eq!(
+            run("laravel:pennant", &["purge", "new-api"]),
+            "```bash\nphp artisan pennant:purge new-api\n```"
+        );
+        assert!(offers("laravel:pennant", &[""], "purge"));
+    }
+
+    #[test]
+    fn telescope_prune_and_clear() {
+        assert_eq!(
+            run("laravel:telescope", &["prune"]),
+            "```bash\nphp artisan telescope:prune --hours=24\n```"
+        );
+        assert_eq!(
+            run("laravel:telescope", &["prune", "--hours=48"]),
+            "```bash\nphp artisan telescope:prune --hours=48\n```"
+        );
+        assert_eq!(
+            run("laravel:telescope", &["clear"]),
+            "```bash\nphp artisan telescope:clear\n```"
+        );
+        for action in ["prune", "clear", "publish"] {
+            assert!(offers("laravel:telescope", &[""], action));
+        }
+    }
+
+    #[test]
+    fn scout_import_and_flush() {
+        assert_eq!(
+            run("laravel:scout", &["import", "App\\Models\\Post"]),
+            "```bash\nphp artisan scout:import \"App\\Models\\Post\"\n```"
+        );
+        assert_eq!(
+            run("laravel:scout", &["flush", "App\\Models\\Post"]),
+            "```bash\nphp artisan scout:flush \"App\\Models\\Post\"\n```"
+        );
+        for action in ["import", "flush", "sync-index-settings"] {
+            assert!(offers("laravel:scout", &[""], action));
+        }
+    }
+
+    #[test]
+    fn octane_default_and_reload() {
+        assert_eq!(
+            run("laravel:octane", &[]),
+            "```bash\nphp artisan octane:status\n```"
+        );
+        assert_eq!(
+            run("laravel:octane", &["reload"]),
+            "```bash\nphp artisan octane:reload\n```"
+        );
+        for action in ["status", "start", "reload", "stop"] {
+            assert!(offers("laravel:octane", &[""], action));
+        }
+    }
+
+    #[test]
+    fn passport_install_and_keys() {
+        assert_eq!(
+            run("laravel:passport", &["install"]),
+            "```bash\nphp artisan passport:install\n```"
+        );
+        assert_eq!(
+            run("laravel:passport", &["keys"]),
+            "```bash\nphp artisan passport:keys\n```"
+        );
+        for action in ["install", "keys", "client"] {
+            assert!(offers("laravel:passport", &[""], action));
+        }
+    }
+
+    #[test]
+    fn combined_lsp_settings() {
+        let settings: LspSettings = serde_json::from_value(serde_json::json!({
+            "initialization_options": {
+                "workspacePath": "/override",
+                "phpBinary": "/usr/local/bin/php",
+            },
+            "settings": { "confirmDestructive": true },
+            "binary": {
+                "arguments": ["--log-level", "debug"],
+                "env": { "LARAVEL_LS_DEBUG": "1" },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            initialization_options(&settings, "/project".to_string()),
+            serde_json::json!({
+                "workspacePath": "/override",
+                "phpBinary": "/usr/local/bin/php",
+            })
+        );
+        assert_eq!(
+            binary_args_and_env(&settings),
+            (
+                args(&["--log-level", "debug"]),
+                vec![("LARAVEL_LS_DEBUG".to_string(), "1".to_string())],
+            )
+        );
+    }
+
+    #[test]
+    fn missing_lsp_settings() {
+        let settings = LspSettings::default();
+        assert_eq!(
+            initialization_options(&settings, "/project".to_string()),
+            serde_json::json!({ "workspacePath": "/project" })
+        );
+        assert_eq!(binary_args_and_env(&settings), (vec![], vec![]));
+    }
+
+    #[test]
+    fn routes_json() {
+        let text = run("laravel:routes", &["--json"]);
+        assert!(text.contains("```bash\nphp artisan route:list --json\n```"));
+        assert!(text.contains("php artisan route:list --json | jq '.[].uri'"));
+        assert!(offers("laravel:routes", &[""], "--json"));
+    }
+
+    #[test]
+    fn make_mail_markdown_view_path() {
+        let text = make_output(&args(&[
+            "mail",
+            "OrderShipped",
+            "--markdown=emails.orders.shipped",
+        ]));
+        assert!(
+            text.contains("php artisan make:mail OrderShipped --markdown=emails.orders.shipped\n")
+        );
+        assert!(text.contains("`resources/views/emails/orders/shipped.blade.php`"));
+    }
+
+    #[test]
+    fn tinker_multiline_expression_is_joined() {
+        assert_eq!(
+            sanitize_tinker_expression("$user = User::first();\n\n  $user->name;\n"),
+            "$user = User::first(); $user->name"
+        );
+    }
+
+    #[test]
+    fn tinker_no_ansi_passthrough() {
+        assert_eq!(
+            run("laravel:tinker", &["--no-ansi", "User::count()"]),
+            "```bash\nphp artisan tinker --no-ansi --execute='User::count()'\n```"
+        );
+        assert!(offers("laravel:tinker", &[""], "--no-ansi"));
+    }
+
+    #[test]
+    fn migrate_rollback_batch() {
+        assert_eq!(
+            run("laravel:migrate", &["rollback", "--batch=3"]),
+            "```bash\nphp artisan migrate:rollback --batch=3\n```"
+        );
+        assert_eq!(
+            run("laravel:migrate", &["rollback", "--step=2", "--batch=3"]),
+            "```bash\nphp artisan migrate:rollback --step=2 --batch=3\n```"
+        );
+        assert!(offers("laravel:migrate", &["rollback", ""], "--batch"));
+    }
+
+    #[test]
+    fn destructive_completions_follow_setting() {
+        for action in DESTRUCTIVE_ACTIONS {
+            assert!(run_on_complete(action, false));
+            assert!(!run_on_complete(action, true));
+        }
+        assert!(run_on_complete("status", true));
+
+        let mut extension = LaravelExtension::new();
+        for confirm_destructive in [false, true] {
+            extension.settings.confirm_destructive = confirm_destructive;
+            let completions = extension
+                .complete_slash_command_argument(slash_command("laravel:migrate"), args(&[""]))
+                .unwrap();
+            for completion in completions {
+                let destructive = DESTRUCTIVE_ACTIONS.contains(&completion.label.as_str());
+                assert_eq!(
+                    completion.run_command,
+                    !(destructive && confirm_destructive)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cached_settings_drive_completions() {
+        let mut extension = LaravelExtension::new();
+        let fresh_runs = |extension: &LaravelExtension| {
+            extension
+                .complete_slash_command_argument(slash_command("laravel:migrate"), args(&[""]))
+                .unwrap()
+                .into_iter()
+                .find(|completion| completion.label == "fresh")
+                .unwrap()
+                .run_command
+        };
+        assert!(fresh_runs(&extension));
+
+        extension.settings = laravel_settings(&lsp_settings_with(
+            serde_json::json!({ "confirmDestructive": true }),
+        ));
+        assert!(!fresh_runs(&extension));
+    }
+
+    #[test]
+    fn settings_are_read_per_field() {
+        let settings = laravel_settings(&lsp_settings_with(serde_json::json!({
+            "confirmDestructive": "true",
+            "unknownOption": 42,
+        })));
+        assert!(settings.confirm_destructive);
+
+        let settings = laravel_settings(&lsp_settings_with(
+            serde_json::json!({ "confirmDestructive": 1 }),
+        ));
+        assert!(!settings.confirm_destructive);
+        assert!(!laravel_settings(&LspSettings::default()).confirm_destructive);
+    }
+
+    #[test]
+    fn make_controller_nested_resource() {
+        let text = make_output(&args(&[
+            "controller",
+            "PostCommentController",
+            "--resource",
+            "--parent=Post",
+        ]));
+        assert!(text.contains(
+            "php artisan make:controller PostCommentController --resource --parent=Post\n"
+        ));
+        assert!(text.contains("`app/Http/Controllers/PostCommentController.php`"));
+        assert!(text.contains("parent `Post` model"));
+        assert!(offers(
+            "laravel:make",
+            &["controller", "PostCommentController", ""],
+            "--parent"
+        ));
+    }
+
+    #[test]
+    fn make_model_all() {
+        assert_eq!(
+            model_companions(&args(&["model", "Post", "--all"])),
+            [
+                "migration",
+                "factory",
+                "seeder",
+                "controller",
+                "policy",
+                "form requests"
+            ]
+        );
+        let text = make_output(&args(&["model", "Post", "--all"]));
+        assert!(text.contains("php artisan make:model Post --all\n"));
+        assert!(text.contains("Multiple files will be created"));
+    }
+
+    #[test]
+    fn make_model_combined_short_flags() {
+        assert_eq!(
+            model_companions(&args(&["model", "Post", "-mf"])),
+            ["migration", "factory"]
+        );
+        assert!(make_output(&args(&["model", "Post", "-mf"]))
+            .contains("the model plus its migration, factory."));
+        assert!(model_companions(&args(&["model", "Post"])).is_empty());
+        assert!(offers("laravel:make", &["model", "Post", ""], "--all"));
+    }
+
+    #[test]
+    fn sectioned_output_ranges_match_text() {
+        let steps = vec![
+            (
+                "Install package".to_string(),
+                "composer require laravel/breeze --dev".to_string(),
+            ),
+            (
+                "Run installer".to_string(),
+                "php artisan breeze:install".to_string(),
+            ),
+        ];
+        let output = build_sectioned_output(&steps);
+        assert_eq!(output.sections.len(), steps.len());
+        for (section, (label, cmd)) in output.sections.iter().zip(&steps) {
+            assert_eq!(&section.label, label);
+            let range = section.range.start as usize..section.range.end as usize;
+            assert_eq!(output.text[range], format!("```bash\n{}\n```", cmd));
+        }
+        assert_eq!(
+            output.sections.last().unwrap().range.end as usize,
+            output.text.len()
+        );
+    }
+
+    #[test]
+    fn db_seed_force_with_class() {
+        assert_eq!(
+            run("laravel:db", &["seed", "--class=UserSeeder", "--force"]),
+            "```bash\nphp artisan db:seed --class=UserSeeder --force\n```"
+        );
+        assert!(offers("laravel:db", &["seed", ""], "--force"));
+    }
+
+    #[test]
+    fn make_factory_usage_model_name() {
+        assert!(make_output(&args(&["factory", "PostFactory"]))
+            .contains("Post::factory()->count(10)->create();"));
+        assert!(make_output(&args(&[
+            "factory",
+            "PostFactory",
+            "--model=App\\Models\\Article"
+        ]))
+        .contains("Article::factory()->count(10)->create();"));
+
+        let nested = make_output(&args(&["factory", "Admin/UserFactory"]));
+        assert!(nested.contains("\nUser::factory()->count(10)->create();"));
+        assert!(!nested.contains("Admin/User::factory()"));
+    }
+
+    #[test]
+    fn routes_sorted() {
+        assert_eq!(
+            run("laravel:routes", &["--sort=uri"]),
+            "Run this command to see all registered routes:\n\n```bash\nphp artisan route:list --sort=uri\n```"
+        );
+        assert!(offers("laravel:routes", &[""], "--sort=name"));
+    }
+
+    #[test]
+    fn make_observer_registration_snippet() {
+        let text = make_output(&args(&["observer", "UserObserver", "--model=User"]));
+        assert!(text.contains("`app/Observers/UserObserver.php`"));
+        assert!(text.contains("#[ObservedBy([UserObserver::class])]\nclass User extends Model"));
+        assert!(text.contains("User::observe(UserObserver::class);"));
+
+        let text = make_output(&args(&["observer", "UserObserver"]));
+        assert!(!text.contains("ObservedBy"));
+    }
+
+    #[test]
+    fn make_observer_nested_name() {
+        let text = make_output(&args(&[
+            "observer",
+            "Admin/UserObserver",
+            "--model=App\\Models\\User",
+        ]));
+        assert!(text.contains("`app/Observers/Admin/UserObserver.php`"));
+        assert!(text.contains("use App\\Observers\\Admin\\UserObserver;"));
+        assert!(text.contains("#[ObservedBy([UserObserver::class])]\nclass User extends Model"));
+        assert!(text.contains("User::observe(UserObserver::class);"));
+        assert!(!text.contains("Admin/UserObserver::class"));
+    }
+
+    #[test]
+    fn queue_listen_with_flags() {
+        let text = run(
+            "laravel:queue",
+            &["listen", "--queue=emails", "--tries=3", "--timeout=60"],
+        );
+        assert!(text.starts_with(
+            "```bash\nphp artisan queue:listen --queue=emails --tries=3 --timeout=60\n```"
+        ));
+        assert!(text.contains("Unlike `queue:work`"));
+        assert!(offers("laravel:queue", &[""], "listen"));
+    }
+
+    #[test]
+    fn make_channel_authorization_hint() {
+        let text = make_output(&args(&["channel", "OrderChannel"]));
+        assert!(text.contains("`app/Broadcasting/OrderChannel.php`"));
+        assert!(text.contains("presence channels"));
+        assert!(text.contains("`routes/channels.php`"));
+        assert!(text.contains("Broadcast::channel('orders.{order}', OrderChannel::class);"));
+    }
+
+    #[test]
+    fn make_channel_nested_name() {
+        let text = make_output(&args(&["channel", "Admin/OrderChannel"]));
+        assert!(text.contains("`app/Broadcasting/Admin/OrderChannel.php`"));
+        assert!(text.contains("use App\\Broadcasting\\Admin\\OrderChannel;"));
+        assert!(text.contains("Broadcast::channel('orders.{order}', OrderChannel::class);"));
+    }
+
+    #[test]
+    fn migrate_pretend() {
+        assert_eq!(
+            run("laravel:migrate", &["run", "--pretend"]),
+            "```bash\nphp artisan migrate --pretend\n```"
+        );
+        assert!(offers("laravel:migrate", &["run", ""], "--pretend"));
+    }
+
+    #[test]
+    fn make_policy_guest_note() {
+        let text = make_output(&args(&["policy", "PostPolicy"]));
+        assert!(text.starts_with(
+            "Run this artisan command in your Laravel project:\n\n```bash\nphp artisan make:policy PostPolicy\n```"
+        ));
+        assert!(text.contains("public function view(?User $user, Post $post): bool"));
+    }
+
+    #[test]
+    fn db_wipe_drop_flags() {
+        assert_eq!(
+            run("laravel:db", &["wipe", "--drop-views"]),
+            "```bash\nphp artisan db:wipe --drop-views\n```"
+        );
+        assert_eq!(
+            run("laravel:db", &["wipe", "--drop-views", "--drop-types"]),
+            "```bash\nphp artisan db:wipe --drop-views --drop-types\n```"
+        );
+        assert!(offers("laravel:db", &["wipe", ""], "--drop-views"));
+        assert!(offers("laravel:db", &["wipe", ""], "--drop-types"));
+    }
+
+    #[test]
+    fn make_command_schedule_hint() {
+        let text = make_output(&args(&["command", "SendEmails"]));
+        assert!(text.contains("`routes/console.php`"));
+        assert!(text.contains("Schedule::command('app:send-emails')->daily();"));
+        assert!(text.contains("`app/Console/Kernel.php`"));
+
+        let custom = make_output(&args(&["command", "SendEmails", "--command=mail:send"]));
+        assert!(custom.contains("Schedule::command('mail:send')->daily();"));
+    }
+
+    #[test]
+    fn make_command_nested_signature() {
+        let text = make_output(&args(&["command", "Admin/SendEmails"]));
+        assert!(text.contains("`app/Console/Commands/Admin/SendEmails.php`"));
+        assert!(text.contains("Schedule::command('app:send-emails')->daily();"));
+        assert!(!text.contains("admin/"));
+    }
+
+    #[test]
+    fn blade_component_filter_range_skips_prefix() {
+        let label =
+            completion_label(completion("alert", Some(zed::lsp::CompletionKind::Module))).unwrap();
+        assert_eq!(label.code, "x-alert");
+        assert_eq!(label.filter_range.start, 2);
+        assert_eq!(label.filter_range.end as usize, label.code.len());
+        assert_eq!(
+            &label.code[label.filter_range.start as usize..label.filter_range.end as usize],
+            "alert"
+        );
+    }
+
+    #[test]
+    fn make_request_rules_snippet() {
+        let text = make_output(&args(&["request", "StoreUserRequest"]));
+        assert!(text.contains("`app/Http/Requests/StoreUserRequest.php`"));
+        assert!(text
+            .contains("public function rules(): array\n{\n    return ['field' => 'required'];\n}"));
+    }
+
+    #[test]
+    fn env_encrypt_and_decrypt() {
+        assert_eq!(
+            run("laravel:env", &["encrypt"]),
+            "```bash\nphp artisan env:encrypt\n```"
+        );
+        assert_eq!(
+            run("laravel:env", &["decrypt", "--key=base64:abc"]),
+            "```bash\nphp artisan env:decrypt --key=base64:abc\n```"
+        );
+        assert!(offers("laravel:env", &[""], "encrypt"));
+        assert!(offers("laravel:env", &[""], "decrypt"));
+        assert!(offers("laravel:env", &["decrypt", ""], "--key"));
+    }
+
+    #[test]
+    fn make_cast_interface_note() {
+        let text = make_output(&args(&["cast", "Json"]));
+        assert!(text.contains("`app/Casts/Json.php`"));
+        assert!(text.contains("This cast implements `CastsAttributes`"));
+
+        let inbound = make_output(&args(&["cast", "Hash", "--inbound"]));
+        assert!(inbound.contains("This cast implements `CastsInboundAttributes`"));
+    }
+
+    #[test]
+    fn make_notification_channels_hint() {
+        let text = make_output(&args(&["notification", "InvoicePaid"]));
+        assert!(text.contains("`mail`, `database`, `broadcast`, or `slack`"));
+        assert!(text.contains("public function via(object $notifiable): array"));
+    }
+
+    #[test]
+    fn routes_except_path() {
+        assert!(run("laravel:routes", &["--except-path=telescope"])
+            .contains("```bash\nphp artisan route:list --except-path=telescope\n```"));
+        assert!(offers("laravel:routes", &[""], "--except-path"));
+    }
+
+    #[test]
+    fn make_listener_event_import() {
+        let text = make_output(&args(&[
+            "listener",
+            "SendShipmentNotification",
+            "--event=OrderShipped",
+        ]));
+        assert!(text.contains("`app/Listeners/SendShipmentNotification.php`"));
+        assert!(text.contains("use App\\Events\\OrderShipped;"));
+
+        let qualified = make_output(&args(&[
+            "listener",
+            "SendShipmentNotification",
+            "--event=\\Domain\\Orders\\OrderShipped",
+        ]));
+        assert!(qualified.contains("use Domain\\Orders\\OrderShipped;"));
+    }
+
+    #[test]
+    fn make_listener_nested_event_import() {
+        let text = make_output(&args(&[
+            "listener",
+            "SendShipmentNotification",
+            "--event=Admin/OrderShipped",
+        ]));
+        assert!(text.contains("use App\\Events\\Admin\\OrderShipped;"));
+    }
+
+    #[test]
+    fn tinker_json_wraps_last_statement() {
+        assert_eq!(
+            tinker_json_expression("User::first()"),
+            "echo json_encode(User::first(), JSON_PRETTY_PRINT)"
+        );
+        assert_eq!(
+            tinker_json_expression("$u = User::first(); $u->name"),
+            "$u = User::first(); echo json_encode($u->name, JSON_PRETTY_PRINT)"
+        );
+        assert_eq!(
+            tinker_json_expression("$s = 'a;b'; strlen($s)"),
+            "$s = 'a;b'; echo json_encode(strlen($s), JSON_PRETTY_PRINT)"
+        );
+        assert_eq!(
+            run("laravel:tinker", &["--json", "User::count()"]),
+            "```bash\nphp artisan tinker --execute='echo json_encode(User::count(), JSON_PRETTY_PRINT)'\n```"
+        );
+        assert!(offers("laravel:tinker", &[""], "--json"));
+    }
+
+    #[test]
+    fn queue_clear_plain_and_scoped() {
+        assert_eq!(
+            run("laravel:queue", &["clear"]),
+            "```bash\nphp artisan queue:clear\n```"
+        );
+        assert_eq!(
+            run("laravel:queue", &["clear", "--queue=emails"]),
+            "```bash\nphp artisan queue:clear --queue=emails\n```"
+        );
+        assert!(offers("laravel:queue", &["clear", ""], "--queue"));
+    }
+
+    #[test]
+    fn schedule_list_timezone() {
+        assert_eq!(
+            run("laravel:schedule", &["list", "--timezone=America/New_York"]),
+            "```bash\nphp artisan schedule:list --timezone=America/New_York\n```"
+        );
+        assert!(offers("laravel:schedule", &["list", ""], "--timezone"));
+    }
+
+    #[test]
+    fn make_provider_laravel_11_registration() {
+        let text = make_output(&args(&["provider", "RiakServiceProvider"]));
+        assert!(text.contains("`app/Providers/RiakServiceProvider.php`"));
+        assert!(text.contains("`bootstrap/providers.php`"));
+        assert!(text.contains("App\\Providers\\RiakServiceProvider::class,"));
+    }
+
+    #[test]
+    fn make_provider_nested_name() {
+        let text = make_output(&args(&["provider", "Admin/RiakServiceProvider"]));
+        assert!(text.contains("`app/Providers/Admin/RiakServiceProvider.php`"));
+        assert!(text.contains("App\\Providers\\Admin\\RiakServiceProvider::class,"));
+    }
+
+    #[test]
+    fn view_clear_storage_note() {
+        let text = run("laravel:view", &["clear"]);
+        assert!(text.starts_with("```bash\nphp artisan view:clear\n```"));
+        assert!(text.contains("`storage/framework/views`"));
+    }
+
+    #[test]
+    fn make_enum_case_matches_backing_type() {
+        assert!(
+            make_output(&args(&["enum", "Status", "--string"])).contains("case Active = 'active';")
+        );
+        assert!(make_output(&args(&["enum", "Status", "--int"])).contains("case Active = 1;"));
+        assert!(!make_output(&args(&["enum", "Status"])).contains("case Active"));
+    }
+
+    #[test]
+    fn make_middleware_registration_hints() {
+        let text = make_output(&args(&["middleware", "EnsureTokenIsValid"]));
+        assert!(text.contains("`bootstrap/app.php`"));
+        assert!(text.contains("$middleware->append(EnsureTokenIsValid::class);"));
+        assert!(text.contains(
+            "$middleware->alias(['ensure-token-is-valid' => EnsureTokenIsValid::class]);"
+        ));
+    }
+
+    #[test]
+    fn make_middleware_nested_name() {
+        let text = make_output(&args(&["middleware", "Admin/EnsureIsAdmin"]));
+        assert!(text.contains("`app/Http/Middleware/Admin/EnsureIsAdmin.php`"));
+        assert!(text.contains("$middleware->append(EnsureIsAdmin::class);"));
+        assert!(text.contains("$middleware->alias(['ensure-is-admin' => EnsureIsAdmin::class]);"));
+        assert!(!text.contains("Admin/EnsureIsAdmin::class"));
+    }
+
+    #[test]
+    fn optimize_clear_cache_list() {
+        let text = run("laravel:optimize", &["clear"]);
+        assert!(text.starts_with("```bash\nphp artisan optimize:clear\n```"));
+        assert!(text.contains(
+            "config, events, routes, and views, plus the compiled services and packages files"
+        ));
+        assert!(text.contains("`cache:clear`, which flushes the entire application cache store"));
+    }
+
+    #[test]
+    fn make_test_nested_name() {
+        assert!(make_output(&args(&["test", "Admin/DashboardTest"]))
+            .contains("`tests/Feature/Admin/DashboardTest.php`"));
+        assert!(
+            make_output(&args(&["test", "Admin\\DashboardTest", "--unit"]))
+                .contains("`tests/Unit/Admin/DashboardTest.php`")
+        );
+    }
+
+    #[test]
+    fn db_monitor_flags() {
+        assert_eq!(
+            run("laravel:db", &["monitor", "--databases=mysql", "--max=100"]),
+            "```bash\nphp artisan db:monitor --databases=mysql --max=100\n```"
+        );
+        assert!(offers("laravel:db", &["monitor", ""], "--databases"));
+        assert!(offers("laravel:db", &["monitor", ""], "--max"));
+    }
+
+    #[test]
+    fn make_job_batchable_note_only_with_flag() {
+        assert!(make_output(&args(&["job", "ImportCsv", "--batched"]))
+            .contains("`Illuminate\\Bus\\Batchable` trait"));
+        assert!(!make_output(&args(&["job", "ImportCsv"])).contains("Batchable"));
+        assert!(offers(
+            "laravel:make",
+            &["job", "ImportCsv", ""],
+            "--batched"
+        ));
+    }
+
+    #[test]
+    fn make_controller_singleton() {
+        let text = run(
+            "laravel:make",
+            &["controller", "ProfileController", "--singleton"],
+        );
+        assert!(text.contains("php artisan make:controller ProfileController --singleton\n"));
+        assert!(offers(
+            "laravel:make",
+            &["controller", "ProfileController", ""],
+            "--singleton"
+        ));
+    }
+
+    #[test]
+    fn make_controller_singleton_conflicts_with_resource() {
+        let error = LaravelExtension::new()
+            .run_slash_command(
+                slash_command("laravel:make"),
+                args(&[
+                    "controller",
+                    "ProfileController",
+                    "--singleton",
+                    "--resource",
+                ]),
+                None,
+            )
+            .unwrap_err();
+        assert!(error.starts_with("--singleton and --resource can't be combined"));
+    }
+
+    #[test]
+    fn make_factory_state_snippet() {
+        let text = make_output(&args(&["factory", "UserFactory"]));
+        assert!(text.contains("public function suspended(): static"));
+        assert!(text.contains("User::factory()->suspended()->create();"));
+    }
+
+    #[test]
+    fn routes_valid_method() {
+        assert!(is_http_verb("GET"));
+        assert!(is_http_verb("patch"));
+        assert!(run("laravel:routes", &["--method=POST"])
+            .contains("```bash\nphp artisan route:list --method=POST\n```"));
+    }
+
+    #[test]
+    fn routes_invalid_method() {
+        assert!(!is_http_verb("FOO"));
+        let error = LaravelExtension::new()
+            .run_slash_command(
+                slash_command("laravel:routes"),
+                args(&["--method=FOO"]),
+                None,
+            )
+            .unwrap_err();
+        assert!(error.starts_with("Invalid --method value `FOO`"));
+    }
+
+    #[test]
+    fn make_listener_connection_note_only_when_queued() {
+        assert!(
+            make_output(&args(&["listener", "SendShipmentNotification", "--queued"]))
+                .contains("public $connection = 'redis';")
+        );
+        assert!(
+            !make_output(&args(&["listener", "SendShipmentNotification"])).contains("$connection")
+        );
+    }
+
+    #[test]
+    fn storage_link_relative() {
+        assert_eq!(
+            run("laravel:storage", &["link", "--relative"]),
+            "```bash\nphp artisan storage:link --relative\n```"
+        );
+        assert!(offers("laravel:storage", &["link", ""], "--relative"));
+    }
+
+    #[test]
+    fn config_cache_closure_warning() {
+        let text = run("laravel:config", &["cache"]);
+        assert!(text.starts_with("```bash\nphp artisan config:cache\n```"));
+        assert!(text.contains("closures can't be serialized"));
+    }
+
+    #[test]
+    fn make_rule_validation_rule_note() {
+        let text = make_output(&args(&["rule", "Uppercase"]));
+        assert!(text.contains("`app/Rules/Uppercase.php`"));
+        assert!(text.contains("implements `ValidationRule`"));
+        assert!(text.contains("legacy `Rule` interface"));
+        assert!(text.contains(
+            "public function validate(string $attribute, mixed $value, Closure $fail): void"
+        ));
+    }
+
+    #[test]
+    fn tinker_heredoc_delimiters_and_body() {
+        assert_eq!(
+            tinker_heredoc("php artisan tinker", "$user = User::first(); $user->name"),
+            "php artisan tinker <<'PHP'\n$user = User::first();\n$user->name;\nPHP"
+        );
+        assert_eq!(
+            run("laravel:tinker", &["--heredoc", "User::count();"]),
+            "```bash\nphp artisan tinker <<'PHP'\nUser::count();\nPHP\n```"
+        );
+        assert!(offers("laravel:tinker", &[""], "--heredoc"));
+    }
+
+    #[test]
+    fn tinker_heredoc_keeps_quoted_semicolons() {
+        assert_eq!(
+            tinker_heredoc("php artisan tinker", "echo 'a;b'"),
+            "php artisan tinker <<'PHP'\necho 'a;b';\nPHP"
+        );
+        assert_eq!(
+            tinker_heredoc("php artisan tinker", "$s = \"x;\\\"y;\"; echo $s"),
+            "php artisan tinker <<'PHP'\n$s = \"x;\\\"y;\";\necho $s;\nPHP"
+        );
+    }
+
+    #[test]
+    fn make_seeder_call_registration() {
+        assert!(make_output(&args(&["seeder", "UserSeeder"]))
+            .contains("$this->call(UserSeeder::class);"));
+        assert!(make_output(&args(&["seeder", "Admin\\RoleSeeder"]))
+            .contains("$this->call(RoleSeeder::class);"));
+    }
+
+    #[test]
+    fn queue_work_memory_and_sleep() {
+        assert_eq!(
+            run("laravel:queue", &["work", "--memory=256", "--sleep=5"]),
+            "```bash\nphp artisan queue:work --memory=256 --sleep=5\n```"
+        );
+        assert!(offers("laravel:queue", &["work", ""], "--memory"));
+        assert!(offers("laravel:queue", &["work", ""], "--sleep"));
+    }
+
+    #[test]
+    fn migrate_install() {
+        assert_eq!(
+            run("laravel:migrate", &["install"]),
+            "```bash\nphp artisan migrate:install\n```"
+        );
+        assert!(offers("laravel:migrate", &[""], "install"));
+    }
+
+    #[test]
+    fn make_policy_resource_methods() {
+        assert!(make_output(&args(&["policy", "PostPolicy"])).contains(
+            "`viewAny`, `view`, `create`, `update`, `delete`, `restore`, and `forceDelete`"
+        ));
+    }
+
+    #[test]
+    fn make_observer_events_list() {
+        let text = make_output(&args(&["observer", "UserObserver"]));
+        for event in [
+            "creating",
+            "created",
+            "updating",
+            "updated",
+            "deleting",
+            "deleted",
+            "restoring",
+            "restored",
+        ] {
+            assert!(text.contains(&format!("`{}`", event)));
+        }
+    }
+
+    #[test]
+    fn db_seed_bare_name_matches_class_flag() {
+        assert_eq!(
+            normalize_seed_args(&args(&["UserSeeder", "--force"])),
+            args(&["--class=UserSeeder", "--force"])
+        );
+        let expected = "```bash\nphp artisan db:seed --class=UserSeeder\n```";
+        assert_eq!(run("laravel:db", &["seed", "UserSeeder"]), expected);
+        assert_eq!(run("laravel:db", &["seed", "--class=UserSeeder"]), expected);
+    }
+
+    #[test]
+    fn db_seed_space_separated_class_flag() {
+        assert_eq!(
+            normalize_seed_args(&args(&["--class", "UserSeeder", "--force"])),
+            args(&["--class=UserSeeder", "--force"])
+        );
+        assert_eq!(
+            run("laravel:db", &["seed", "--class", "UserSeeder"]),
+            "```bash\nphp artisan db:seed --class=UserSeeder\n```"
+        );
+    }
+
+    #[test]
+    fn routes_name_filter() {
+        assert!(run("laravel:routes", &["--name=users"])
+            .contains("```bash\nphp artisan route:list --name=users\n```"));
+        assert!(offers("laravel:routes", &[""], "--name"));
+    }
+
+    #[test]
+    fn make_controller_model_binding_note() {
+        let text = make_output(&args(&[
+            "controller",
+            "PhotoController",
+            "--resource",
+            "--model=Photo",
+        ]));
+        assert!(text.contains("type-hint `Photo`"));
+        assert!(text.contains("`show(Photo $photo)`"));
+        assert!(offers(
+            "laravel:make",
+            &["controller", "PhotoController", ""],
+            "--model"
+        ));
+    }
+
+    #[test]
+    fn make_controller_model_binding_variable() {
+        assert_eq!(lcfirst("BlogPost"), "blogPost");
+        let text = make_output(&args(&[
+            "controller",
+            "BlogPostController",
+            "--resource",
+            "--model=BlogPost",
+        ]));
+        assert!(text.contains("`show(BlogPost $blogPost)`"));
+        let text = make_output(&args(&[
+            "controller",
+            "PostController",
+            "--resource",
+            "--model=App/Models/Post",
+        ]));
+        assert!(text.contains("`show(Post $post)`"));
+    }
+
+    #[test]
+    fn make_event_broadcast_now_note() {
+        let text = make_output(&args(&["event", "OrderShipped"]));
+        assert!(text.contains("`ShouldBroadcast`"));
+        assert!(text.contains("`ShouldBroadcastNow`"));
+    }
+
+    #[test]
+    fn make_mail_queue_note() {
+        let text = make_output(&args(&["mail", "OrderShipped"]));
+        assert!(text.contains("`app/Mail/OrderShipped.php`"));
+        assert!(text.contains("`Illuminate\\Contracts\\Queue\\ShouldQueue`"));
+    }
+
+    #[test]
+    fn make_notification_table_hint() {
+        let text = make_output(&args(&["notification", "InvoicePaid"]));
+        assert!(text.contains("php artisan notifications:table\nphp artisan migrate"));
+        assert!(text.contains("`php artisan make:notifications-table`"));
+    }
+
+    #[test]
+    fn schedule_work_cron_note() {
+        let text = run("laravel:schedule", &["work"]);
+        assert!(text.starts_with("```bash\nphp artisan schedule:work\n```"));
+        assert!(text.contains("in the foreground"));
+        assert!(text.contains(
+            "* * * * * cd /path-to-your-project && php artisan schedule:run >> /dev/null 2>&1"
+        ));
+    }
+
+    #[test]
+    fn optimize_deploy_note() {
+        let text = run("laravel:optimize", &[]);
+        assert!(text.starts_with("```bash\nphp artisan optimize\n```"));
+        assert!(text.contains("part of your deploy"));
+        assert!(text.contains("caches the config, events, routes, and views"));
+        assert!(offers("laravel:optimize", &[""], "clear"));
+    }
+
+    #[test]
+    fn make_command_handle_stub() {
+        let text = make_output(&args(&["command", "SendEmails"]));
+        assert!(text.contains("protected $signature = 'app:send-emails {user} {--queue}';"));
+        assert!(text.contains("public function handle(): int"));
+        assert!(text.contains("$this->argument('user')"));
+        assert!(text.contains("$this->option('queue')"));
+    }
+
+    #[test]
+    fn db_show_connection() {
+        assert_eq!(
+            run("laravel:db", &["show", "--database=pgsql"]),
+            "```bash\nphp artisan db:show --database=pgsql\n```"
+        );
+        assert!(offers("laravel:db", &["show", ""], "--database"));
+    }
+
+    #[test]
+    fn make_request_authorize_snippet() {
+        assert!(make_output(&args(&["request", "StoreUserRequest"]))
+            .contains("public function authorize(): bool\n{\n    return true;\n}"));
+    }
+
+    #[test]
+    fn make_factory_definition_stub() {
+        let text = make_output(&args(&["factory", "UserFactory"]));
+        assert!(text.contains("`database/factories/UserFactory.php`"));
+        assert!(text.contains("public function definition(): array"));
+        assert!(text.contains("'name' => fake()->name(),"));
+    }
+
+    #[test]
+    fn queue_retry_batch() {
+        assert_eq!(
+            run("laravel:queue", &["retry-batch", "9a1b2c3d"]),
+            "```bash\nphp artisan queue:retry-batch 9a1b2c3d\n```"
+        );
+        assert!(run("laravel:queue", &["retry-batch"]).starts_with("Usage: /laravel:queue"));
+    }
+
+    #[test]
+    fn make_test_group_hint_by_framework() {
+        let phpunit = make_output(&args(&["test", "ChargeTest"]));
+        assert!(phpunit.contains("#[Group('billing')]"));
+        assert!(phpunit.contains("`@group`"));
+        assert!(!phpunit.contains("->group("));
+
+        let pest = make_output(&args(&["test", "ChargeTest", "--pest"]));
+        assert!(pest.contains("})->group('billing');"));
+        assert!(!pest.contains("#[Group"));
+    }
+
+    #[test]
+    fn make_job_routing_note() {
+        let text = make_output(&args(&["job", "ProcessPodcast"]));
+        assert!(text.contains("`app/Jobs/ProcessPodcast.php`"));
+        assert!(text.contains("    $this->onQueue('emails');\n    $this->onConnection('redis');"));
+        assert!(text.contains("`->onQueue()`"));
+        assert!(!text.contains("public $queue"));
+    }
+
+    #[test]
+    fn schedule_run_note() {
+        let text = run("laravel:schedule", &["run"]);
+        assert!(text.starts_with("```bash\nphp artisan schedule:run\n```"));
+        assert!(text.contains("executes every task that is due right now"));
+        assert!(text.contains("unlike `schedule:work`"));
+    }
+
+    #[test]
+    fn make_cast_method_stubs() {
+        let text = make_output(&args(&["cast", "Json"]));
+        assert!(text.contains("public function get(Model $model, string $key, mixed $value, array $attributes): mixed"));
+        assert!(text.contains("public function set(Model $model, string $key, mixed $value, array $attributes): mixed"));
+
+        // Inbound casts only transform writes
+        let inbound = make_output(&args(&["cast", "Hash", "--inbound"]));
+        assert!(inbound.contains("public function set("));
+        assert!(!inbound.contains("public function get("));
+    }
+
+    #[test]
+    fn make_channel_join_stub() {
+        assert!(make_output(&args(&["channel", "OrderChannel"]))
+            .contains("public function join(User $user, Order $order): array|bool"));
+    }
+
+    #[test]
+    fn make_provider_register_and_boot_stubs() {
+        let text = make_output(&args(&["provider", "RiakServiceProvider"]));
+        assert!(text.contains("public function register(): void"));
+        assert!(text.contains("public function boot(): void"));
+    }
+
+    #[test]
+    fn make_scope_apply_stub() {
+        let text = make_output(&args(&["scope", "ActiveScope"]));
+        assert!(text.contains("`app/Models/Scopes/ActiveScope.php`"));
+        assert!(text.contains("public function apply(Builder $builder, Model $model): void"));
+    }
+
+    #[test]
+    fn make_middleware_handle_stub() {
+        let text = make_output(&args(&["middleware", "EnsureTokenIsValid"]));
+        assert!(text.contains("public function handle(Request $request, Closure $next): Response"));
+        assert!(text.contains("$response = $next($request);"));
+    }
+
+    #[test]
+    fn make_exception_stubs_follow_flags() {
+        let report = make_output(&args(&["exception", "InvalidOrder", "--report"]));
+        assert!(report.contains("`app/Exceptions/InvalidOrder.php`"));
+        assert!(report.contains("public function report(): bool"));
+        assert!(report.contains("return false;"));
+        assert!(!report.contains("public function render("));
+
+        let render = make_output(&args(&["exception", "InvalidOrder", "--render"]));
+        assert!(render.contains("public function render(Request $request): Response"));
+        assert!(!render.contains("public function report("));
+
+        let plain = make_output(&args(&["exception", "InvalidOrder"]));
+        assert!(!plain.contains("public function"));
+    }
+
+    #[test]
+    fn make_resource_to_array_stub() {
+        let text = make_output(&args(&["resource", "UserResource"]));
+        assert!(text.contains("`app/Http/Resources/UserResource.php`"));
+        assert!(text.contains("public function toArray(Request $request): array"));
+        assert!(text.contains("'id' => $this->id,"));
+    }
+}